@@ -4,10 +4,11 @@
 use std::collections::BTreeMap;
 
 use criterion::{BenchmarkId, Criterion, Throughput};
-use rand_core::{CryptoRng, RngCore};
+use rand_chacha::ChaCha20Rng;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
 
 use crate as frost;
-use crate::{batch, Ciphersuite, Signature, SigningKey, VerifyingKey};
+use crate::{batch, Ciphersuite, Field, Group, Signature, SigningKey, VerifyingKey};
 
 struct Item<C: Ciphersuite> {
     vk: VerifyingKey<C>,
@@ -195,3 +196,334 @@ pub fn bench_sign<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
     }
     group.finish();
 }
+
+/// Benchmark computing the group commitment and binding factors with the
+/// specified ciphersuite.
+pub fn bench_group_commitment<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
+    c: &mut Criterion,
+    name: &str,
+    rng: &mut R,
+) {
+    let mut group = c.benchmark_group(format!("FROST Group Commitment {name}"));
+    for &n in [200u16].iter() {
+        let mut commitments: BTreeMap<_, _> = BTreeMap::new();
+        for participant_index in 1..=n {
+            let participant_identifier: frost::Identifier<C> =
+                participant_index.try_into().expect("should be nonzero");
+            let signing_share =
+                frost::keys::SigningShare(<<C::Group as Group>::Field>::random(rng));
+            let (_nonce, commitment) = frost::round1::commit(&signing_share, rng);
+            commitments.insert(participant_identifier, commitment);
+        }
+
+        let verifying_key = VerifyingKey::from(&SigningKey::new(rng));
+        let message = "message to sign".as_bytes();
+        let signing_package = frost::SigningPackage::new(commitments, message);
+
+        group.bench_with_input(
+            BenchmarkId::new("Combined", n),
+            &(signing_package, verifying_key),
+            |b, (signing_package, verifying_key)| {
+                b.iter(|| {
+                    frost::compute_group_commitment_and_binding_factors(
+                        signing_package,
+                        verifying_key,
+                        &[],
+                    )
+                    .unwrap();
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Benchmark verifying the signature shares of a signing operation one by one
+/// and in a batch, with the specified ciphersuite.
+pub fn bench_verify_signature_shares_batch<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
+    c: &mut Criterion,
+    name: &str,
+    rng: &mut R,
+) {
+    let mut group = c.benchmark_group(format!("FROST Signature Share Verification {name}"));
+    for &n in [50u16].iter() {
+        let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, R>(
+            n,
+            n,
+            frost::keys::IdentifierList::Default,
+            rng,
+        )
+        .unwrap();
+
+        let key_packages: BTreeMap<_, _> = shares
+            .into_iter()
+            .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+            .collect();
+
+        let mut nonces = BTreeMap::new();
+        let mut commitments = BTreeMap::new();
+        for (identifier, key_package) in &key_packages {
+            let (nonce, commitment) = frost::round1::commit(key_package.signing_share(), rng);
+            nonces.insert(*identifier, nonce);
+            commitments.insert(*identifier, commitment);
+        }
+
+        let message = "message to sign".as_bytes();
+        let signing_package = frost::SigningPackage::new(commitments, message);
+
+        let signature_shares: BTreeMap<_, _> = key_packages
+            .iter()
+            .map(|(identifier, key_package)| {
+                let signature_share = frost::round2::sign(
+                    &signing_package,
+                    nonces.get(identifier).unwrap(),
+                    key_package,
+                )
+                .unwrap();
+                (*identifier, signature_share)
+            })
+            .collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("Individual", n),
+            &(
+                signing_package.clone(),
+                pubkeys.clone(),
+                signature_shares.clone(),
+            ),
+            |b, (signing_package, pubkeys, signature_shares)| {
+                b.iter(|| {
+                    let (group_commitment, binding_factor_list) =
+                        frost::compute_group_commitment_and_binding_factors(
+                            signing_package,
+                            pubkeys.verifying_key(),
+                            &[],
+                        )
+                        .unwrap();
+                    let challenge =
+                        signing_package.challenge(&group_commitment, pubkeys.verifying_key());
+                    frost::verify_signature_shares(
+                        signing_package,
+                        pubkeys,
+                        signature_shares,
+                        &binding_factor_list,
+                        &challenge,
+                    )
+                    .unwrap();
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Batch", n),
+            &(signing_package, pubkeys, signature_shares),
+            |b, (signing_package, pubkeys, signature_shares)| {
+                let mut rng = rng.clone();
+                b.iter(|| {
+                    frost::verify_signature_shares_batch(
+                        signing_package,
+                        pubkeys,
+                        signature_shares,
+                        &mut rng,
+                    )
+                    .unwrap();
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Benchmark signing repeatedly with the same set of signers, with and without
+/// caching the Lagrange coefficients.
+pub fn bench_lambda_cache<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
+    c: &mut Criterion,
+    name: &str,
+    rng: &mut R,
+) {
+    let mut group = c.benchmark_group(format!("FROST Lagrange Coefficient Cache {name}"));
+    for &n in [10u16, 100, 1000].iter() {
+        let max_signers = n;
+        let min_signers = (n * 2 + 2) / 3;
+
+        let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, R>(
+            max_signers,
+            min_signers,
+            frost::keys::IdentifierList::Default,
+            rng,
+        )
+        .unwrap();
+
+        let mut key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> =
+            BTreeMap::new();
+
+        for (k, v) in shares {
+            key_packages.insert(k, frost::keys::KeyPackage::try_from(v).unwrap());
+        }
+
+        let mut nonces: BTreeMap<_, _> = BTreeMap::new();
+        let mut commitments: BTreeMap<_, _> = BTreeMap::new();
+
+        for participant_index in 1..=min_signers {
+            let participant_identifier = participant_index.try_into().expect("should be nonzero");
+            let (nonce, commitment) = frost::round1::commit(
+                key_packages
+                    .get(&participant_identifier)
+                    .unwrap()
+                    .signing_share(),
+                rng,
+            );
+            nonces.insert(participant_identifier, nonce);
+            commitments.insert(participant_identifier, commitment);
+        }
+
+        let message = "message to sign".as_bytes();
+        let signing_package = frost::SigningPackage::new(commitments, message);
+        let participant_identifier = 1u16.try_into().expect("should be nonzero");
+        let key_package = key_packages.get(&participant_identifier).unwrap();
+        let nonces_to_use = nonces.get(&participant_identifier).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("Uncached", min_signers),
+            &(key_package, nonces_to_use),
+            |b, (key_package, nonces_to_use)| {
+                b.iter(|| {
+                    frost::round2::sign(&signing_package, nonces_to_use, key_package).unwrap();
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Cached", min_signers),
+            &(key_package, nonces_to_use),
+            |b, (key_package, nonces_to_use)| {
+                let mut cache = frost::round2::LambdaCache::new();
+                b.iter(|| {
+                    frost::round2::sign_cached(
+                        &signing_package,
+                        nonces_to_use,
+                        key_package,
+                        &mut cache,
+                    )
+                    .unwrap();
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The seed of the RNG used by [`bench_signing_set_sizes`].
+const BENCH_SEED: [u8; 32] = [0x42; 32];
+
+/// Benchmark each step of a signing operation, from key generation with a
+/// trusted dealer to verifying the aggregated signature, for several sizes
+/// of the signing set, with the specified ciphersuite.
+///
+/// Every participant takes part in signing (i.e. `min_signers` equals
+/// `max_signers`), so the size is the number of signers. All keys, nonces and
+/// messages are derived from a fixed seed, so that runs are reproducible and
+/// can be compared with each other.
+pub fn bench_signing_set_sizes<C: Ciphersuite>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(format!("FROST Signing Set Sizes {name}"));
+    for &n in [2u16, 3, 10, 50, 100].iter() {
+        let mut rng = ChaCha20Rng::from_seed(BENCH_SEED);
+
+        group.bench_with_input(
+            BenchmarkId::new("Key Generation with Dealer", n),
+            &n,
+            |b, n| {
+                let mut rng = rng.clone();
+                b.iter(|| {
+                    frost::keys::generate_with_dealer::<C, _>(
+                        *n,
+                        *n,
+                        frost::keys::IdentifierList::Default,
+                        &mut rng,
+                    )
+                    .unwrap();
+                })
+            },
+        );
+
+        let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+            n,
+            n,
+            frost::keys::IdentifierList::Default,
+            &mut rng,
+        )
+        .unwrap();
+        let key_packages: BTreeMap<_, _> = shares
+            .into_iter()
+            .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+            .collect();
+        let participant_identifier: frost::Identifier<C> =
+            1u16.try_into().expect("should be nonzero");
+        let key_package = key_packages.get(&participant_identifier).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("Round 1", n),
+            key_package,
+            |b, key_package| {
+                let mut rng = rng.clone();
+                b.iter(|| {
+                    frost::round1::commit(key_package.signing_share(), &mut rng);
+                })
+            },
+        );
+
+        let mut nonces = BTreeMap::new();
+        let mut commitments = BTreeMap::new();
+        for (identifier, key_package) in &key_packages {
+            let (nonce, commitment) = frost::round1::commit(key_package.signing_share(), &mut rng);
+            nonces.insert(*identifier, nonce);
+            commitments.insert(*identifier, commitment);
+        }
+        let message = "message to sign".as_bytes();
+        let signing_package = frost::SigningPackage::new(commitments, message);
+
+        group.bench_with_input(
+            BenchmarkId::new("Round 2", n),
+            &(key_package, nonces.get(&participant_identifier).unwrap()),
+            |b, (key_package, nonces_to_use)| {
+                b.iter(|| {
+                    frost::round2::sign(&signing_package, nonces_to_use, key_package).unwrap();
+                })
+            },
+        );
+
+        let mut signature_shares = BTreeMap::new();
+        for (identifier, key_package) in &key_packages {
+            let signature_share = frost::round2::sign(
+                &signing_package,
+                nonces.get(identifier).unwrap(),
+                key_package,
+            )
+            .unwrap();
+            signature_shares.insert(*identifier, signature_share);
+        }
+
+        group.bench_with_input(
+            BenchmarkId::new("Aggregate", n),
+            &(&signature_shares, &pubkeys),
+            |b, (signature_shares, pubkeys)| {
+                b.iter(|| {
+                    frost::aggregate(&signing_package, signature_shares, pubkeys).unwrap();
+                })
+            },
+        );
+
+        let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("Verify", n),
+            &(pubkeys.verifying_key(), signature),
+            |b, (verifying_key, signature)| {
+                b.iter(|| {
+                    verifying_key.verify(message, signature).unwrap();
+                })
+            },
+        );
+    }
+    group.finish();
+}
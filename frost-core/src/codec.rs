@@ -0,0 +1,99 @@
+//! Codecs to encode the structs that need to be communicated with a chosen
+//! `serde` data format.
+//!
+//! The `serialize()` and `deserialize()` methods of those structs always use
+//! the default byte-oriented encoding, which is the [`Postcard`] codec. The
+//! codecs in this module let an application pick another format and use it
+//! uniformly for all of them, e.g. `Json::encode(&signing_package)`. Each
+//! codec other than [`Postcard`] is enabled by its own feature.
+//!
+//! Different codecs produce incompatible encodings: decoding the output of a
+//! codec with another one returns [`Error::DeserializationError`].
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{Ciphersuite, Error};
+
+/// A `serde` data format used to encode and decode the structs that need to
+/// be communicated.
+pub trait Codec<C: Ciphersuite> {
+    /// Encode `value` into a Vec.
+    ///
+    /// Returns [`Error::SerializationError`] if it can't be encoded.
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Error<C>>;
+
+    /// Decode a value from a slice of bytes.
+    ///
+    /// Returns [`Error::DeserializationError`] if the bytes are not a valid
+    /// encoding of a `T` in this format.
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error<C>>;
+}
+
+/// The [Postcard](https://docs.rs/postcard) codec, which is the encoding used
+/// by the `serialize()` and `deserialize()` methods of the structs.
+#[cfg(feature = "serialization")]
+#[derive(Clone, Copy, Debug)]
+pub struct Postcard;
+
+#[cfg(feature = "serialization")]
+impl<C: Ciphersuite> Codec<C> for Postcard {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Error<C>> {
+        postcard::to_stdvec(value).map_err(|_| Error::SerializationError)
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error<C>> {
+        postcard::from_bytes(bytes).map_err(|_| Error::DeserializationError)
+    }
+}
+
+/// The JSON codec. Scalars and elements are encoded as hex strings, and the
+/// ciphersuite as its ID string.
+#[cfg(feature = "json")]
+#[derive(Clone, Copy, Debug)]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl<C: Ciphersuite> Codec<C> for Json {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Error<C>> {
+        serde_json::to_vec(value).map_err(|_| Error::SerializationError)
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error<C>> {
+        serde_json::from_slice(bytes).map_err(|_| Error::DeserializationError)
+    }
+}
+
+/// The [CBOR](https://www.rfc-editor.org/rfc/rfc8949) codec.
+#[cfg(feature = "cbor")]
+#[derive(Clone, Copy, Debug)]
+pub struct Cbor;
+
+#[cfg(feature = "cbor")]
+impl<C: Ciphersuite> Codec<C> for Cbor {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Error<C>> {
+        let mut bytes = vec![];
+        ciborium::ser::into_writer(value, &mut bytes).map_err(|_| Error::SerializationError)?;
+        Ok(bytes)
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error<C>> {
+        ciborium::de::from_reader(bytes).map_err(|_| Error::DeserializationError)
+    }
+}
+
+/// The [MessagePack](https://msgpack.org) codec. Structs are encoded as maps
+/// with their field names.
+#[cfg(feature = "msgpack")]
+#[derive(Clone, Copy, Debug)]
+pub struct MessagePack;
+
+#[cfg(feature = "msgpack")]
+impl<C: Ciphersuite> Codec<C> for MessagePack {
+    fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, Error<C>> {
+        rmp_serde::to_vec_named(value).map_err(|_| Error::SerializationError)
+    }
+
+    fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error<C>> {
+        rmp_serde::from_slice(bytes).map_err(|_| Error::DeserializationError)
+    }
+}
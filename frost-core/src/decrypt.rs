@@ -0,0 +1,168 @@
+//! Threshold ElGamal decryption with FROST key shares.
+//!
+//! The group secret shared by a FROST key generation can also be used to
+//! decrypt ElGamal ciphertexts encrypted to the group [`VerifyingKey`],
+//! without running a new key generation. A plaintext is a group element `M`,
+//! encrypted with a random `r` as `(c1, c2) = (r * G, M + r * PK)`.
+//!
+//! To decrypt, a set of at least `min_signers` participants is chosen, and
+//! each of them computes a [`DecryptionShare`] with [`decryption_share`]. The
+//! shares are then combined with [`combine_decryption_shares`] to recover `M`.
+//!
+//! # Security
+//!
+//! Decryption shares are not verifiable: a participant can send an incorrect
+//! share and cause a wrong plaintext to be recovered, and this module can't
+//! tell who did it. Using the same key for signing and decryption is also
+//! against the usual advice of key separation; applications should make sure
+//! that decryption can't be used as an oracle to attack signing, e.g. by never
+//! decrypting ciphertexts with a `c1` that was used elsewhere.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Debug},
+};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    compute_lagrange_coefficient, keys, random_nonzero, Ciphersuite, Element, Error, Group,
+    Identifier, VerifyingKey,
+};
+
+/// An ElGamal ciphertext encrypted to a group [`VerifyingKey`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Ciphertext<C: Ciphersuite> {
+    /// The ephemeral public key `r * G`.
+    pub(crate) c1: Element<C>,
+    /// The masked plaintext `M + r * PK`.
+    pub(crate) c2: Element<C>,
+}
+
+impl<C> Ciphertext<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`Ciphertext`] from its components.
+    pub fn new(c1: Element<C>, c2: Element<C>) -> Self {
+        Self { c1, c2 }
+    }
+
+    /// Encrypts the `plaintext` group element to the `verifying_key`.
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        verifying_key: &VerifyingKey<C>,
+        plaintext: &Element<C>,
+        rng: &mut R,
+    ) -> Self {
+        let r = random_nonzero::<C, R>(rng);
+
+        Self {
+            c1: <C::Group>::generator() * r,
+            c2: *plaintext + verifying_key.element * r,
+        }
+    }
+
+    /// Gets the ephemeral public key `c1`.
+    pub fn c1(&self) -> &Element<C> {
+        &self.c1
+    }
+
+    /// Gets the masked plaintext `c2`.
+    pub fn c2(&self) -> &Element<C> {
+        &self.c2
+    }
+}
+
+impl<C> Debug for Ciphertext<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ciphertext")
+            .field("c1", &hex::encode(<C::Group>::serialize(&self.c1)))
+            .field("c2", &hex::encode(<C::Group>::serialize(&self.c2)))
+            .finish()
+    }
+}
+
+/// A participant's share of the decryption of a [`Ciphertext`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DecryptionShare<C: Ciphersuite> {
+    /// `lambda_i * signing_share * c1`.
+    pub(crate) share: Element<C>,
+}
+
+impl<C> DecryptionShare<C>
+where
+    C: Ciphersuite,
+{
+    /// Deserialize [`DecryptionShare`] from bytes.
+    pub fn deserialize(bytes: <C::Group as Group>::Serialization) -> Result<Self, Error<C>> {
+        <C::Group>::deserialize(&bytes)
+            .map(|share| Self { share })
+            .map_err(|e| e.into())
+    }
+
+    /// Serialize [`DecryptionShare`] to bytes.
+    pub fn serialize(&self) -> <C::Group as Group>::Serialization {
+        <C::Group>::serialize(&self.share)
+    }
+}
+
+impl<C> Debug for DecryptionShare<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("DecryptionShare")
+            .field(&hex::encode(self.serialize()))
+            .finish()
+    }
+}
+
+/// Performed once by each participant selected for the decryption, i.e. in
+/// `decryptors`.
+///
+/// Returns [`Error::UnknownIdentifier`] if the participant is not in
+/// `decryptors`, and [`Error::IncorrectNumberOfShares`] if there are fewer
+/// than `min_signers` decryptors.
+pub fn decryption_share<C: Ciphersuite>(
+    ciphertext: &Ciphertext<C>,
+    key_package: &keys::KeyPackage<C>,
+    decryptors: &BTreeSet<Identifier<C>>,
+) -> Result<DecryptionShare<C>, Error<C>> {
+    if !decryptors.contains(&key_package.identifier) {
+        return Err(Error::UnknownIdentifier {
+            identifier: key_package.identifier,
+        });
+    }
+    if decryptors.len() < key_package.min_signers as usize {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+
+    let lambda_i = compute_lagrange_coefficient(decryptors, None, key_package.identifier)?;
+
+    Ok(DecryptionShare {
+        share: ciphertext.c1 * (lambda_i * key_package.signing_share.0),
+    })
+}
+
+/// Combines the decryption shares of all the decryptors to recover the
+/// plaintext group element.
+///
+/// The shares must have been computed for the same set of decryptors as the
+/// keys of `decryption_shares`; otherwise a wrong plaintext is returned.
+pub fn combine_decryption_shares<C: Ciphersuite>(
+    ciphertext: &Ciphertext<C>,
+    decryption_shares: &BTreeMap<Identifier<C>, DecryptionShare<C>>,
+) -> Result<Element<C>, Error<C>> {
+    if decryption_shares.is_empty() {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+
+    let mask = decryption_shares
+        .values()
+        .fold(<C::Group>::identity(), |acc, share| acc + share.share);
+
+    Ok(ciphertext.c2 - mask)
+}
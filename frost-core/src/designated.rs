@@ -0,0 +1,265 @@
+//! Designated-verifier FROST signatures.
+//!
+//! In regular FROST the challenge is a hash of the group commitment, so
+//! anyone can check a signature and be convinced that the group signed the
+//! message. Here the challenge is instead derived from a [`VerifierNonce`]
+//! chosen by a designated verifier, which makes the signature an interactive
+//! Schnorr proof that only convinces that verifier.
+//!
+//! The flow is:
+//!
+//! 1. The verifier generates a [`VerifierNonce`] and publishes its
+//!    [`VerifierCommitment`], before any signer reveals their round 1
+//!    commitments.
+//! 2. The signers run round 1 as usual; the coordinator builds a
+//!    [`SigningPackage`], whose binding factors are bound to the verifier
+//!    commitment.
+//! 3. Once the [`SigningPackage`] is fixed, the verifier reveals the
+//!    [`VerifierNonce`]. Each signer calls [`sign`], which checks the nonce
+//!    against the commitment.
+//! 4. The coordinator calls [`aggregate`], and the verifier checks the
+//!    signature with [`verify`].
+//!
+//! # Security
+//!
+//! Since the challenge doesn't depend on the group commitment, the designated
+//! verifier can produce valid signatures on their own: picking a random `z`
+//! and setting `R = z * G - c * PK` for the challenge `c` derived from their
+//! nonce gives a signature that verifies. Therefore a signature (and the
+//! nonce) convinces no one but the verifier, who knows they did not produce
+//! it; i.e. designated-verifier signatures are non-transferable.
+//!
+//! This also means the verifier must commit to the nonce before seeing the
+//! signers' commitments and reveal it only after the [`SigningPackage`] is
+//! fixed; signers *MUST* check the revealed nonce against the commitment,
+//! which [`sign`] does.
+
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    check_signature_shares_match, compute_group_commitment_and_binding_factors,
+    derive_interpolating_value, keys, round1, round2, verify_signature_shares, Challenge,
+    Ciphersuite, Error, Field, Group, Identifier, Signature, SigningPackage, VerifyingKey,
+};
+
+/// The domain separator for the commitment to a [`VerifierNonce`].
+const COMMITMENT_DOMAIN: &[u8] = b"designated verifier commitment";
+
+/// The domain separator for the challenge of a designated-verifier signature.
+const CHALLENGE_DOMAIN: &[u8] = b"designated verifier challenge";
+
+/// A random nonce chosen by the designated verifier, from which the challenge
+/// of the signature is derived.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifierNonce<C: Ciphersuite> {
+    bytes: [u8; 32],
+    phantom: PhantomData<C>,
+}
+
+impl<C> VerifierNonce<C>
+where
+    C: Ciphersuite,
+{
+    /// Generate a new random nonce.
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        Self::deserialize(bytes)
+    }
+
+    /// Computes the commitment to this nonce, to be published by the verifier
+    /// before the signers' commitments are revealed.
+    pub fn commit(&self) -> VerifierCommitment<C> {
+        let mut preimage = vec![];
+        preimage.extend_from_slice(COMMITMENT_DOMAIN);
+        preimage.extend_from_slice(&self.bytes);
+
+        VerifierCommitment {
+            bytes: C::H4(&preimage).as_ref().to_vec(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Serialize the nonce to bytes.
+    pub fn serialize(&self) -> [u8; 32] {
+        self.bytes
+    }
+
+    /// Deserialize the nonce from bytes.
+    pub fn deserialize(bytes: [u8; 32]) -> Self {
+        Self {
+            bytes,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// The commitment to a [`VerifierNonce`], published by the designated
+/// verifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifierCommitment<C: Ciphersuite> {
+    bytes: Vec<u8>,
+    phantom: PhantomData<C>,
+}
+
+impl<C> VerifierCommitment<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the commitment to bytes.
+    pub fn serialize(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    /// Deserialize the commitment from bytes.
+    pub fn deserialize(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Checks that `nonce` is the one this commitment was computed from.
+    fn check(&self, nonce: &VerifierNonce<C>) -> Result<(), Error<C>> {
+        if nonce.commit() != *self {
+            return Err(Error::InvalidVerifierNonce);
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the challenge of a designated-verifier signature, which unlike
+/// the regular FROST challenge doesn't depend on the group commitment.
+pub(crate) fn challenge<C: Ciphersuite>(
+    verifying_key: &VerifyingKey<C>,
+    msg: &[u8],
+    verifier_nonce: &VerifierNonce<C>,
+) -> Challenge<C> {
+    let mut preimage = vec![];
+
+    preimage.extend_from_slice(CHALLENGE_DOMAIN);
+    preimage.extend_from_slice(<C::Group>::serialize(&verifying_key.element).as_ref());
+    preimage.extend_from_slice(&verifier_nonce.bytes);
+    preimage.extend_from_slice(msg);
+
+    Challenge(C::H2(&preimage[..]))
+}
+
+/// Performed once by each participant selected for the signing operation,
+/// after the designated verifier revealed their nonce.
+///
+/// Like [`round2::sign`], but binds the signature share to the designated
+/// verifier. Returns [`Error::InvalidVerifierNonce`] if `verifier_nonce`
+/// doesn't match `verifier_commitment`.
+pub fn sign<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &keys::KeyPackage<C>,
+    verifier_commitment: &VerifierCommitment<C>,
+    verifier_nonce: &VerifierNonce<C>,
+) -> Result<round2::SignatureShare<C>, Error<C>> {
+    verifier_commitment.check(verifier_nonce)?;
+
+    // The binding factors are bound to the verifier commitment so that the
+    // group commitment can't be chosen after the challenge is known, and the
+    // challenge is computed from the verifier nonce.
+    round2::sign_with_lambda(
+        signing_package,
+        signer_nonces,
+        key_package.identifier,
+        &key_package.verifying_key,
+        key_package.min_signers,
+        &key_package.signing_share,
+        || derive_interpolating_value(key_package.identifier(), signing_package),
+        &verifier_commitment.bytes,
+        |_group_commitment| {
+            challenge(
+                &key_package.verifying_key,
+                signing_package.message().as_slice(),
+                verifier_nonce,
+            )
+        },
+    )
+}
+
+/// Aggregates the signature shares of a designated-verifier signing operation
+/// into a signature that only convinces the designated verifier.
+///
+/// Like [`crate::aggregate`], verifies each share if the resulting signature
+/// is invalid, to find the culprits.
+pub fn aggregate<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    verifier_commitment: &VerifierCommitment<C>,
+    verifier_nonce: &VerifierNonce<C>,
+) -> Result<Signature<C>, Error<C>> {
+    verifier_commitment.check(verifier_nonce)?;
+
+    check_signature_shares_match(signing_package, signature_shares)?;
+
+    let (group_commitment, binding_factor_list) = compute_group_commitment_and_binding_factors(
+        signing_package,
+        &pubkeys.verifying_key,
+        &verifier_commitment.bytes,
+    )?;
+
+    let mut z = <<C::Group as Group>::Field>::zero();
+
+    for signature_share in signature_shares.values() {
+        z = z + signature_share.share;
+    }
+
+    let signature = Signature::from_components(group_commitment, z);
+
+    if verify(
+        &pubkeys.verifying_key,
+        signing_package.message(),
+        &signature,
+        verifier_nonce,
+    )
+    .is_ok()
+    {
+        return Ok(signature);
+    }
+
+    // Verify each share to find the cheater.
+    let challenge = challenge(
+        &pubkeys.verifying_key,
+        signing_package.message().as_slice(),
+        verifier_nonce,
+    );
+
+    verify_signature_shares(
+        signing_package,
+        pubkeys,
+        signature_shares,
+        &binding_factor_list,
+        &challenge,
+    )?;
+
+    Err(Error::InvalidSignature)
+}
+
+/// Verifies a designated-verifier signature on `msg`, using the nonce the
+/// designated verifier chose.
+///
+/// The signature does not verify with any other nonce, and only convinces the
+/// designated verifier; see the [module documentation](self).
+pub fn verify<C: Ciphersuite>(
+    verifying_key: &VerifyingKey<C>,
+    msg: &[u8],
+    signature: &Signature<C>,
+    verifier_nonce: &VerifierNonce<C>,
+) -> Result<(), Error<C>> {
+    let challenge = challenge(verifying_key, msg, verifier_nonce);
+
+    if <C::Group>::generator() * signature.z != signature.R + verifying_key.element * challenge.0 {
+        return Err(Error::InvalidSignature);
+    }
+
+    Ok(())
+}
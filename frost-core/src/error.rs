@@ -1,5 +1,7 @@
 //! FROST Error types
 
+use std::collections::BTreeSet;
+
 use thiserror::Error;
 
 use crate::{Ciphersuite, Identifier};
@@ -9,7 +11,7 @@ pub struct ParticipantError<C: Ciphersuite>(Identifier<C>);
 
 /// An error related to FROST.
 #[non_exhaustive]
-#[derive(Error, Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
 pub enum Error<C: Ciphersuite> {
     /// min_signers is invalid
     #[error("min_signers must be at least 2 and not larger than max_signers")]
@@ -24,11 +26,17 @@ pub enum Error<C: Ciphersuite> {
     #[error("Malformed identifier is unserializable.")]
     MalformedIdentifier,
     /// This identifier is duplicated.
-    #[error("Duplicated identifier.")]
-    DuplicatedIdentifier,
+    #[error("Duplicated identifier {identifier:?}.")]
+    DuplicatedIdentifier {
+        /// The identifier that appears more than once.
+        identifier: Identifier<C>,
+    },
     /// This identifier does not belong to a participant in the signing process.
-    #[error("Unknown identifier.")]
-    UnknownIdentifier,
+    #[error("Unknown identifier {identifier:?}.")]
+    UnknownIdentifier {
+        /// The identifier that is not a participant.
+        identifier: Identifier<C>,
+    },
     /// Incorrect number of identifiers.
     #[error("Incorrect number of identifiers.")]
     IncorrectNumberOfIdentifiers,
@@ -44,6 +52,9 @@ pub enum Error<C: Ciphersuite> {
     /// Signature verification failed.
     #[error("Invalid signature.")]
     InvalidSignature,
+    /// A signature on the same message by the same group was already accepted.
+    #[error("A signature on this message by this group was already accepted.")]
+    ReplayDetected,
     /// Duplicated shares provided
     #[error("Duplicated shares provided.")]
     DuplicatedShares,
@@ -56,21 +67,98 @@ pub enum Error<C: Ciphersuite> {
     /// The participant's commitment is missing from the Signing Package
     #[error("The Signing Package must contain the participant's Commitment.")]
     MissingCommitment,
+    /// The message to sign was not set
+    #[error("The message to sign was not set.")]
+    MissingMessage,
+    /// The participant was not selected for this signing round
+    #[error("The participant is not part of the Signing Package's signing set.")]
+    NotInSigningSet,
     /// The participant's commitment is incorrect
     #[error("The participant's commitment is incorrect.")]
     IncorrectCommitment,
     /// Incorrect number of commitments.
     #[error("Incorrect number of commitments.")]
     IncorrectNumberOfCommitments,
+    /// Fewer commitments than the threshold.
+    #[error("Too few commitments: have {have}, need at least {need}.")]
+    TooFewCommitments {
+        /// The number of commitments in the signing package.
+        have: usize,
+        /// The minimum number of commitments, i.e. min_signers.
+        need: usize,
+    },
+    /// More commitments than participants in the group.
+    #[error("Too many commitments: have {have}, at most {max} allowed.")]
+    TooManyCommitments {
+        /// The number of commitments in the signing package.
+        have: usize,
+        /// The maximum number of commitments, i.e. max_signers.
+        max: usize,
+    },
+    /// The participant's commitment was invalidated by restarting round 1.
+    #[error("The commitment from participant {culprit} was invalidated by a restart of round 1.")]
+    StaleCommitment {
+        /// The identifier of the participant whose commitment is stale.
+        culprit: Identifier<C>,
+    },
+    /// The participant's commitment is not a valid, canonically encoded,
+    /// non-identity group element.
+    #[error("Invalid commitment from participant {culprit}.")]
+    InvalidCommitment {
+        /// The identifier of the participant whose commitment is invalid.
+        culprit: Identifier<C>,
+    },
+    /// A participant's share of the group commitment doesn't match their
+    /// commitments in the signing package.
+    #[error(
+        "The group commitment share of participant {culprit} doesn't match their commitments."
+    )]
+    InconsistentCommitmentShare {
+        /// The identifier of the participant whose share is inconsistent.
+        culprit: Identifier<C>,
+    },
+    /// A participant signed a different signing package than the one being
+    /// aggregated, e.g. one with another message.
+    #[error("Participant {culprit} signed a different signing package.")]
+    SigningPackageMismatch {
+        /// The identifier of the participant who signed another package.
+        culprit: Identifier<C>,
+    },
+    /// Distinct participants sent identical commitments, which means that
+    /// they share nonces, e.g. because of a bug or a leak.
+    #[error("Distinct participants sent identical commitments.")]
+    SuspiciousCommitments,
     /// Signature share verification failed.
-    #[error("Invalid signature share.")]
+    #[error("Invalid signature share from participant {culprit}.")]
     InvalidSignatureShare {
         /// The identifier of the signer whose share validation failed.
         culprit: Identifier<C>,
     },
+    /// Verification of one or more of the signature shares of a signing
+    /// operation failed.
+    #[error("Invalid signature shares from participants {culprits:?}.")]
+    InvalidSignatureShares {
+        /// The identifiers of all the signers whose share validation failed.
+        culprits: BTreeSet<Identifier<C>>,
+    },
+    /// The encoding of a signature share was malformed.
+    #[error("Malformed signature share encoding from participant {culprit}.")]
+    MalformedSignatureShare {
+        /// The identifier of the signer whose share could not be deserialized.
+        culprit: Identifier<C>,
+    },
+    /// The verifying shares of the signers don't match the group verifying key.
+    #[error("The verifying shares don't match the group verifying key.")]
+    MismatchedGroupPublicKey,
     /// Secret share verification failed.
-    #[error("Invalid secret share.")]
-    InvalidSecretShare,
+    #[error("Invalid secret share for participant {culprit}.")]
+    InvalidSecretShare {
+        /// The identifier of the participant whose secret share is invalid.
+        culprit: Identifier<C>,
+    },
+    /// The secret shares were not generated with the same commitment.
+    #[error("The secret shares have different commitments.")]
+    MismatchedCommitments,
     /// Round 1 package not found for Round 2 participant.
     #[error("Round 1 package not found for Round 2 participant.")]
     PackageNotFound,
@@ -84,7 +172,7 @@ pub enum Error<C: Ciphersuite> {
     #[error("The ciphersuite does not support DKG.")]
     DKGNotSupported,
     /// The proof of knowledge is not valid.
-    #[error("The proof of knowledge is not valid.")]
+    #[error("The proof of knowledge from participant {culprit} is not valid.")]
     InvalidProofOfKnowledge {
         /// The identifier of the signer whose share validation failed.
         culprit: Identifier<C>,
@@ -98,9 +186,20 @@ pub enum Error<C: Ciphersuite> {
     /// Error in coefficient commitment deserialization.
     #[error("Invalid coefficient")]
     InvalidCoefficient,
+    /// The number of coefficient commitments doesn't match the threshold.
+    #[error("Incorrect number of coefficient commitments: expected {expected}, got {got}.")]
+    InvalidCoefficientCount {
+        /// The expected number of coefficient commitments, i.e. min_signers.
+        expected: usize,
+        /// The number of coefficient commitments received.
+        got: usize,
+    },
     /// The ciphersuite does not support deriving identifiers from strings.
     #[error("The ciphersuite does not support deriving identifiers from strings.")]
     IdentifierDerivationNotSupported,
+    /// The designated verifier nonce doesn't match its commitment.
+    #[error("The designated verifier nonce doesn't match its commitment.")]
+    InvalidVerifierNonce,
     /// Error serializing value.
     #[error("Error serializing value.")]
     SerializationError,
@@ -118,6 +217,10 @@ where
     ///
     /// This can be used to penalize a participant that does not follow the
     /// protocol correctly, e.g. removing them from further signings.
+    ///
+    /// For [`Error::InvalidSignatureShares`], which can have several culprits,
+    /// this returns the one with the smallest identifier; use
+    /// [`Error::culprits`] to get all of them.
     pub fn culprit(&self) -> Option<Identifier<C>> {
         // Use an exhaustive match to make sure that if we add new enum items
         // then we will explicitly check if they should be added here.
@@ -127,9 +230,27 @@ where
             }
             | Error::InvalidProofOfKnowledge {
                 culprit: identifier,
+            }
+            | Error::MalformedSignatureShare {
+                culprit: identifier,
+            }
+            | Error::InvalidSecretShare {
+                culprit: identifier,
+            }
+            | Error::StaleCommitment {
+                culprit: identifier,
+            }
+            | Error::InvalidCommitment {
+                culprit: identifier,
+            }
+            | Error::InconsistentCommitmentShare {
+                culprit: identifier,
+            }
+            | Error::SigningPackageMismatch {
+                culprit: identifier,
             } => Some(*identifier),
-            Error::InvalidSecretShare
-            | Error::InvalidMinSigners
+            Error::InvalidSignatureShares { culprits } => culprits.iter().next().copied(),
+            Error::InvalidMinSigners
             | Error::InvalidMaxSigners
             | Error::InvalidCoefficients
             | Error::MalformedIdentifier
@@ -137,25 +258,47 @@ where
             | Error::MalformedVerifyingKey
             | Error::MalformedSignature
             | Error::InvalidSignature
+            | Error::ReplayDetected
+            | Error::MismatchedGroupPublicKey
+            | Error::MismatchedCommitments
             | Error::DuplicatedShares
             | Error::IncorrectNumberOfShares
             | Error::IdentityCommitment
             | Error::MissingCommitment
+            | Error::MissingMessage
+            | Error::NotInSigningSet
             | Error::IncorrectCommitment
+            | Error::SuspiciousCommitments
             | Error::PackageNotFound
             | Error::IncorrectNumberOfPackages
             | Error::IncorrectPackage
             | Error::DKGNotSupported
             | Error::FieldError(_)
             | Error::GroupError(_)
-            | Error::DuplicatedIdentifier
+            | Error::DuplicatedIdentifier { .. }
             | Error::InvalidCoefficient
-            | Error::UnknownIdentifier
+            | Error::InvalidCoefficientCount { .. }
+            | Error::UnknownIdentifier { .. }
             | Error::IncorrectNumberOfIdentifiers
             | Error::IncorrectNumberOfCommitments
+            | Error::TooFewCommitments { .. }
+            | Error::TooManyCommitments { .. }
             | Error::SerializationError
             | Error::DeserializationError
-            | Error::IdentifierDerivationNotSupported => None,
+            | Error::IdentifierDerivationNotSupported
+            | Error::InvalidVerifierNonce => None,
+        }
+    }
+
+    /// Return the identifiers of all the participants that caused the error,
+    /// which is empty if not applicable for the error.
+    ///
+    /// This allows removing every misbehaving participant at once, e.g. all
+    /// the signers whose signature shares were invalid.
+    pub fn culprits(&self) -> BTreeSet<Identifier<C>> {
+        match self {
+            Error::InvalidSignatureShares { culprits } => culprits.clone(),
+            _ => self.culprit().into_iter().collect(),
         }
     }
 }
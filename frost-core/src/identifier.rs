@@ -1,11 +1,12 @@
 //! FROST participant identifiers
 
 use std::{
+    collections::BTreeSet,
     fmt::{self, Debug},
     hash::{Hash, Hasher},
 };
 
-use crate::{Ciphersuite, Error, Field, FieldError, Group, Scalar};
+use crate::{keys::VerifyingShare, Ciphersuite, Error, Field, FieldError, Group, Scalar};
 
 #[cfg(feature = "serde")]
 use crate::serialization::ScalarSerialization;
@@ -49,6 +50,51 @@ where
         Self::new(scalar)
     }
 
+    /// Derive an Identifier from a participant's [`VerifyingShare`], by
+    /// deriving it from the serialized share like [`Identifier::derive`].
+    ///
+    /// This is useful in protocols where participants are identified by a
+    /// long-term key, so that the identifier is bound to that key. The same
+    /// verifying share always maps to the same identifier.
+    ///
+    /// Note that in regular key generation the verifying share is computed
+    /// from the identifier, so this can only be used in protocols where the
+    /// keys are assigned before the identifiers, e.g. when the identifiers of
+    /// a signing group are derived from keys the participants already hold.
+    pub fn derive_from_verifying_share(
+        verifying_share: &VerifyingShare<C>,
+    ) -> Result<Self, Error<C>> {
+        Self::derive(verifying_share.serialize().as_ref())
+    }
+
+    /// Checks that there are no duplicates in `identifiers`, e.g. before
+    /// using them as custom identifiers in key generation.
+    ///
+    /// Returns [`Error::DuplicatedIdentifier`] when reaching the first
+    /// identifier that was already seen.
+    pub fn check_unique(identifiers: &[Identifier<C>]) -> Result<(), Error<C>> {
+        let mut seen = BTreeSet::new();
+        for identifier in identifiers {
+            if !seen.insert(identifier) {
+                return Err(Error::DuplicatedIdentifier {
+                    identifier: *identifier,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the identifiers in `identifiers` sorted, with duplicates
+    /// removed.
+    pub fn sorted_unique(identifiers: &[Identifier<C>]) -> Vec<Identifier<C>> {
+        identifiers
+            .iter()
+            .cloned()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
     /// Serialize the identifier using the ciphersuite encoding.
     pub fn serialize(&self) -> <<C::Group as Group>::Field as Field>::Serialization {
         <<C::Group as Group>::Field>::serialize(&self.0)
@@ -101,6 +147,24 @@ where
     }
 }
 
+/// Displays a short fingerprint of the identifier: the first and last four
+/// bytes of its serialization, in hex. Use [`Debug`] to display all bytes.
+impl<C> fmt::Display for Identifier<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let serialized = <<C::Group as Group>::Field>::serialize(&self.0);
+        let bytes = serialized.as_ref();
+        match (bytes.get(..4), bytes.get(bytes.len().saturating_sub(4)..)) {
+            (Some(first), Some(last)) if bytes.len() > 8 => {
+                write!(f, "id:0x{}…{}", hex::encode(first), hex::encode(last))
+            }
+            _ => write!(f, "id:0x{}", hex::encode(bytes)),
+        }
+    }
+}
+
 #[allow(clippy::derived_hash_with_manual_eq)]
 impl<C> Hash for Identifier<C>
 where
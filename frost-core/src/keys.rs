@@ -2,7 +2,7 @@
 #![allow(clippy::type_complexity)]
 
 use std::{
-    collections::{BTreeMap, BTreeSet, HashSet},
+    collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
     default::Default,
     fmt::{self, Debug},
@@ -14,11 +14,12 @@ use derive_getters::Getters;
 use hex::FromHex;
 
 use rand_core::{CryptoRng, RngCore};
-use zeroize::{DefaultIsZeroes, Zeroize};
+use zeroize::{DefaultIsZeroes, Zeroize, ZeroizeOnDrop};
 
 use crate::{
+    scalar_mul::VartimeMultiscalarMul,
     serialization::{Deserialize, Serialize},
-    Ciphersuite, Element, Error, Field, Group, Header, Identifier, Scalar, SigningKey,
+    Challenge, Ciphersuite, Element, Error, Field, Group, Header, Identifier, Scalar, SigningKey,
     VerifyingKey,
 };
 
@@ -238,6 +239,114 @@ where
         // what is passed to the functions.
         VerifyingShare(evaluate_vss(identifier, commitment))
     }
+
+    /// Verifies a [`PossessionProof`] created by the participant with the
+    /// given `identifier`, i.e. that they hold the signing share matching
+    /// this verifying share.
+    ///
+    /// The `nonce` and `context` must be the ones the proof was created with;
+    /// see [`KeyPackage::prove_possession`].
+    ///
+    /// Returns [`Error::InvalidProofOfKnowledge`] if the proof is invalid.
+    pub fn verify_possession(
+        &self,
+        identifier: Identifier<C>,
+        nonce: &[u8],
+        context: Option<&[u8]>,
+        proof: &PossessionProof<C>,
+    ) -> Result<(), Error<C>> {
+        let c = possession_challenge(identifier, self, nonce, context, &proof.R);
+        if proof.R != <C::Group>::generator() * proof.z - self.0 * c.0 {
+            return Err(Error::InvalidProofOfKnowledge {
+                culprit: identifier,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The domain separator for the challenge of a [`PossessionProof`].
+const POSSESSION_DOMAIN: &[u8] = b"signing share possession";
+
+/// Generates the challenge for the proof of possession of a signing share.
+///
+/// The `nonce` and the optional `context` are length-prefixed so that distinct
+/// values can't produce the same preimage.
+fn possession_challenge<C>(
+    identifier: Identifier<C>,
+    verifying_share: &VerifyingShare<C>,
+    nonce: &[u8],
+    context: Option<&[u8]>,
+    R: &Element<C>,
+) -> Challenge<C>
+where
+    C: Ciphersuite,
+{
+    let mut preimage = vec![];
+
+    preimage.extend_from_slice(POSSESSION_DOMAIN);
+    preimage.extend_from_slice(identifier.serialize().as_ref());
+    preimage.extend_from_slice(<C::Group>::serialize(&verifying_share.0).as_ref());
+    preimage.extend_from_slice(&(nonce.len() as u64).to_be_bytes());
+    preimage.extend_from_slice(nonce);
+    match context {
+        Some(context) => {
+            preimage.push(1);
+            preimage.extend_from_slice(&(context.len() as u64).to_be_bytes());
+            preimage.extend_from_slice(context);
+        }
+        None => preimage.push(0),
+    }
+    preimage.extend_from_slice(<C::Group>::serialize(R).as_ref());
+
+    Challenge(C::H2(&preimage[..]))
+}
+
+/// A Schnorr proof of knowledge of a participant's [`SigningShare`] relative
+/// to their [`VerifyingShare`], created with [`KeyPackage::prove_possession`].
+///
+/// This allows a participant to prove they still hold a valid share, e.g. for
+/// health checks, without revealing it.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct PossessionProof<C: Ciphersuite> {
+    /// The commitment to the proof nonce.
+    pub(crate) R: Element<C>,
+    /// The response to the challenge.
+    pub(crate) z: Scalar<C>,
+}
+
+impl<C> PossessionProof<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the proof to bytes, in the same encoding as a [`crate::Signature`].
+    pub fn serialize(&self) -> C::SignatureSerialization {
+        crate::Signature::<C> {
+            R: self.R,
+            z: self.z,
+        }
+        .serialize()
+    }
+
+    /// Deserialize a proof from bytes, in the same encoding as a [`crate::Signature`].
+    pub fn deserialize(bytes: C::SignatureSerialization) -> Result<Self, Error<C>> {
+        let signature = crate::Signature::<C>::deserialize(bytes)?;
+        Ok(Self {
+            R: signature.R,
+            z: signature.z,
+        })
+    }
+}
+
+impl<C> Debug for PossessionProof<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("PossessionProof")
+            .field(&hex::encode(self.serialize()))
+            .finish()
+    }
 }
 
 impl<C> Debug for VerifyingShare<C>
@@ -256,7 +365,7 @@ where
     C: Ciphersuite,
 {
     fn from(secret: SigningShare<C>) -> VerifyingShare<C> {
-        VerifyingShare(<C::Group>::generator() * secret.0 as Scalar<C>)
+        VerifyingShare(<C::Group>::generator() * secret.0)
     }
 }
 
@@ -405,12 +514,38 @@ where
 
     /// Get the VerifyingKey matching this commitment vector (which is the first
     /// element in the vector), or an error if the vector is empty.
-    pub(crate) fn verifying_key(&self) -> Result<VerifyingKey<C>, Error<C>> {
+    ///
+    /// This is the group verifying key committed to by the dealer, and only
+    /// needs the constant term of the commitment.
+    pub fn verifying_key(&self) -> Result<VerifyingKey<C>, Error<C>> {
         Ok(VerifyingKey::new(
             self.0.get(0).ok_or(Error::MissingCommitment)?.0,
         ))
     }
 
+    /// Checks that this commitment is to the `expected` group verifying key,
+    /// e.g. the one advertised for the group, returning
+    /// [`Error::MismatchedGroupPublicKey`] otherwise.
+    pub fn check_verifying_key(&self, expected: &VerifyingKey<C>) -> Result<(), Error<C>> {
+        if self.verifying_key()? != *expected {
+            return Err(Error::MismatchedGroupPublicKey);
+        }
+        Ok(())
+    }
+
+    /// Checks that the commitment has one coefficient commitment for each
+    /// coefficient of a polynomial with the given threshold, i.e. that its
+    /// length is `min_signers`.
+    pub(crate) fn check_min_signers(&self, min_signers: u16) -> Result<(), Error<C>> {
+        if self.0.len() != min_signers as usize {
+            return Err(Error::InvalidCoefficientCount {
+                expected: min_signers as usize,
+                got: self.0.len(),
+            });
+        }
+        Ok(())
+    }
+
     /// Returns the coefficient commitments.
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     pub(crate) fn coefficients(&self) -> &[CoefficientCommitment<C>] {
@@ -484,13 +619,41 @@ where
         let result = evaluate_vss(self.identifier, &self.commitment);
 
         if !(f_result == result) {
-            return Err(Error::InvalidSecretShare);
+            return Err(Error::InvalidSecretShare {
+                culprit: self.identifier,
+            });
         }
 
         Ok((VerifyingShare(result), self.commitment.verifying_key()?))
     }
+
+    /// Like [`SecretShare::verify`], but first checks that the commitment has
+    /// one coefficient commitment for each coefficient of a polynomial with
+    /// the expected threshold `min_signers`.
+    ///
+    /// Otherwise a commitment with coefficient commitments removed or added
+    /// would silently change the threshold of the share. Returns
+    /// [`Error::InvalidCoefficientCount`] if the length doesn't match.
+    pub fn verify_with_min_signers(
+        &self,
+        min_signers: u16,
+    ) -> Result<(VerifyingShare<C>, VerifyingKey<C>), Error<C>> {
+        self.commitment.check_min_signers(min_signers)?;
+        self.verify()
+    }
+}
+
+impl<C> Drop for SecretShare<C>
+where
+    C: Ciphersuite,
+{
+    fn drop(&mut self) {
+        self.zeroize()
+    }
 }
 
+impl<C> ZeroizeOnDrop for SecretShare<C> where C: Ciphersuite {}
+
 #[cfg(feature = "serialization")]
 impl<C> SecretShare<C>
 where
@@ -507,6 +670,61 @@ where
     }
 }
 
+/// Verifies many [`SecretShare`]s generated from the same commitment at once.
+///
+/// Instead of evaluating the commitment for each share like
+/// [`SecretShare::verify`] does, this checks a random linear combination of
+/// all the shares with a single multiscalar multiplication over the
+/// commitment. If that check fails, the shares are verified one by one to
+/// find the first invalid one.
+///
+/// Shares whose commitment differs from the commitment of the first share are
+/// considered invalid.
+pub fn verify_shares<C: Ciphersuite, R: RngCore + CryptoRng>(
+    shares: &[SecretShare<C>],
+    mut rng: R,
+) -> Result<(), Error<C>> {
+    let commitment = match shares.first() {
+        Some(share) => &share.commitment,
+        None => return Ok(()),
+    };
+
+    let mut signing_share_sum = <<C::Group as Group>::Field>::zero();
+    let mut coefficient_scalars = vec![<<C::Group as Group>::Field>::zero(); commitment.0.len()];
+
+    for share in shares {
+        if &share.commitment != commitment {
+            return Err(Error::InvalidSecretShare {
+                culprit: share.identifier,
+            });
+        }
+
+        let r = <<C::Group as Group>::Field>::random(&mut rng);
+        signing_share_sum = signing_share_sum + r * share.signing_share.0;
+
+        // Accumulate r * i^k for each coefficient commitment φ_k.
+        let mut r_i_to_the_k = r;
+        for coefficient_scalar in coefficient_scalars.iter_mut() {
+            *coefficient_scalar = *coefficient_scalar + r_i_to_the_k;
+            r_i_to_the_k *= share.identifier;
+        }
+    }
+
+    let f_result = <C::Group>::generator() * signing_share_sum;
+    let result: Element<C> = VartimeMultiscalarMul::<C>::vartime_multiscalar_mul(
+        coefficient_scalars,
+        commitment.0.iter().map(|c| c.0),
+    );
+
+    if f_result != result {
+        for share in shares {
+            share.verify()?;
+        }
+    }
+
+    Ok(())
+}
+
 /// The identifier list to use when generating key shares.
 pub enum IdentifierList<'a, C: Ciphersuite> {
     /// Use the default values (1 to max_signers, inclusive).
@@ -574,6 +792,63 @@ pub fn split<C: Ciphersuite, R: RngCore + CryptoRng>(
             generate_secret_shares(key, max_signers, min_signers, coefficients, identifiers)?
         }
     };
+
+    Ok(index_secret_shares(secret_shares, verifying_key))
+}
+
+/// Generates FROST shares from a fully specified secret polynomial, without
+/// using any randomness.
+///
+/// `coefficients[0]` is the secret (the constant term of the polynomial) and
+/// the remaining coefficients are those of increasing degree, so the
+/// threshold `min_signers` is `coefficients.len()`. A share is generated for
+/// each of the `identifiers`.
+///
+/// This is useful for ceremonies where the randomness is generated and
+/// audited externally; the caller is responsible for the coefficients being
+/// uniformly random and kept secret. Returns [`Error::MalformedSigningKey`] if
+/// the secret is zero and [`Error::InvalidCoefficients`] if the
+/// highest-degree coefficient is zero, since the threshold would then be
+/// lower than `coefficients.len()`.
+pub fn keygen_from_coefficients<C: Ciphersuite>(
+    coefficients: &[Scalar<C>],
+    identifiers: &[Identifier<C>],
+) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+    let min_signers = u16::try_from(coefficients.len()).map_err(|_| Error::InvalidMinSigners)?;
+    let max_signers = u16::try_from(identifiers.len()).map_err(|_| Error::InvalidMaxSigners)?;
+    validate_num_of_signers(min_signers, max_signers)?;
+
+    let zero = <<C::Group as Group>::Field>::zero();
+    let (secret, coefficients) = coefficients
+        .split_first()
+        .expect("validated to have at least two elements");
+    if *secret == zero {
+        return Err(Error::MalformedSigningKey);
+    }
+    if coefficients.last() == Some(&zero) {
+        return Err(Error::InvalidCoefficients);
+    }
+
+    let key = SigningKey { scalar: *secret };
+    let verifying_key = VerifyingKey::from(&key);
+
+    let secret_shares = generate_secret_shares(
+        &key,
+        max_signers,
+        min_signers,
+        coefficients.to_vec(),
+        identifiers,
+    )?;
+
+    Ok(index_secret_shares(secret_shares, verifying_key))
+}
+
+/// Indexes the secret shares by identifier and builds the matching
+/// [`PublicKeyPackage`].
+fn index_secret_shares<C: Ciphersuite>(
+    secret_shares: Vec<SecretShare<C>>,
+    verifying_key: VerifyingKey<C>,
+) -> (BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>) {
     let mut verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>> = BTreeMap::new();
 
     let mut secret_shares_by_id: BTreeMap<Identifier<C>, SecretShare<C>> = BTreeMap::new();
@@ -585,14 +860,14 @@ pub fn split<C: Ciphersuite, R: RngCore + CryptoRng>(
         secret_shares_by_id.insert(secret_share.identifier, secret_share);
     }
 
-    Ok((
+    (
         secret_shares_by_id,
         PublicKeyPackage {
             header: Header::default(),
             verifying_shares,
             verifying_key,
         },
-    ))
+    )
 }
 
 /// Evaluate the polynomial with the given coefficients (constant term first)
@@ -686,8 +961,94 @@ where
             min_signers,
         }
     }
+
+    /// Checks that this [`KeyPackage`] is consistent with the group's view
+    /// in the given [`PublicKeyPackage`], i.e. that the verifying share the
+    /// group has for this participant is the one derived from its signing
+    /// share, and that both refer to the same verifying key.
+    ///
+    /// Signing with a mismatched configuration would produce signature shares
+    /// that fail verification, so signers can call this before signing.
+    pub fn check_against_public_package(
+        &self,
+        public_package: &PublicKeyPackage<C>,
+    ) -> Result<(), Error<C>> {
+        let verifying_share = public_package
+            .verifying_shares
+            .get(&self.identifier)
+            .ok_or(Error::UnknownIdentifier {
+                identifier: self.identifier,
+            })?;
+
+        if *verifying_share != VerifyingShare::from(self.signing_share) {
+            return Err(Error::InvalidSecretShare {
+                culprit: self.identifier,
+            });
+        }
+
+        if public_package.verifying_key != self.verifying_key {
+            return Err(Error::MismatchedGroupPublicKey);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that this [`KeyPackage`] is consistent with the given
+    /// [`VerifiableSecretSharingCommitment`], i.e. that the verifying share
+    /// matches the commitment evaluated at this participant's identifier, and
+    /// that the verifying key is the one committed to.
+    ///
+    /// This allows detecting a [`KeyPackage`] that was tampered with, e.g.
+    /// after being received over an untrusted channel.
+    pub fn verify_against_commitment(
+        &self,
+        commitment: &VerifiableSecretSharingCommitment<C>,
+    ) -> Result<(), Error<C>> {
+        if self.verifying_share != VerifyingShare::from_commitment(self.identifier, commitment) {
+            return Err(Error::InvalidSecretShare {
+                culprit: self.identifier,
+            });
+        }
+
+        if self.verifying_key != commitment.verifying_key()? {
+            return Err(Error::MismatchedGroupPublicKey);
+        }
+
+        Ok(())
+    }
+
+    /// Creates a [`PossessionProof`] proving that this participant holds the
+    /// signing share matching their verifying share, without revealing it.
+    ///
+    /// The proof is bound to the `nonce`, which must be a fresh challenge
+    /// chosen by the verifier so that an old proof can't be replayed, and to
+    /// the optional `context`, e.g. the name of the audit. It can be checked
+    /// with [`VerifyingShare::verify_possession`] given the same values.
+    pub fn prove_possession<R: RngCore + CryptoRng>(
+        &self,
+        nonce: &[u8],
+        context: Option<&[u8]>,
+        mut rng: R,
+    ) -> PossessionProof<C> {
+        let k = <<C::Group as Group>::Field>::random(&mut rng);
+        let R = <C::Group>::generator() * k;
+        let c = possession_challenge(self.identifier, &self.verifying_share, nonce, context, &R);
+        let z = k + self.signing_share.0 * c.0;
+        PossessionProof { R, z }
+    }
+}
+
+impl<C> Drop for KeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn drop(&mut self) {
+        self.zeroize()
+    }
 }
 
+impl<C> ZeroizeOnDrop for KeyPackage<C> where C: Ciphersuite {}
+
 #[cfg(feature = "serialization")]
 impl<C> KeyPackage<C>
 where
@@ -704,6 +1065,39 @@ where
     }
 }
 
+#[cfg(feature = "serialization")]
+impl<C> TryFrom<&[u8]> for KeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    type Error = Error<C>;
+
+    /// Deserializes a [`KeyPackage`] serialized with [`KeyPackage::serialize`],
+    /// checking that the encoding is canonical and consistent.
+    ///
+    /// The encoding is the serialization header followed by the identifier,
+    /// signing share, verifying share, verifying key and `min_signers`, in
+    /// that order. Unlike [`KeyPackage::deserialize`], this rejects inputs
+    /// with trailing bytes or that don't re-serialize to the same bytes
+    /// ([`Error::DeserializationError`]), and packages whose verifying share
+    /// doesn't match the signing share ([`Error::InvalidSecretShare`]).
+    fn try_from(bytes: &[u8]) -> Result<Self, Error<C>> {
+        let key_package = Self::deserialize(bytes)?;
+
+        if key_package.serialize()? != bytes {
+            return Err(Error::DeserializationError);
+        }
+
+        if VerifyingShare::from(key_package.signing_share) != key_package.verifying_share {
+            return Err(Error::InvalidSecretShare {
+                culprit: key_package.identifier,
+            });
+        }
+
+        Ok(key_package)
+    }
+}
+
 impl<C> TryFrom<SecretShare<C>> for KeyPackage<C>
 where
     C: Ciphersuite,
@@ -718,6 +1112,11 @@ where
     /// every participant has the same view of the commitment issued by the
     /// dealer, but implementations *MUST* make sure that all participants have
     /// a consistent view of this commitment in practice.
+    ///
+    /// The threshold of the [`KeyPackage`] is the number of coefficient
+    /// commitments in the commitment; call
+    /// [`SecretShare::verify_with_min_signers`] first to check it against the
+    /// expected threshold.
     fn try_from(secret_share: SecretShare<C>) -> Result<Self, Error<C>> {
         let (verifying_share, verifying_key) = secret_share.verify()?;
 
@@ -745,7 +1144,8 @@ pub struct PublicKeyPackage<C: Ciphersuite> {
     #[getter(skip)]
     pub(crate) header: Header<C>,
     /// The verifying shares for all participants. Used to validate signature
-    /// shares they generate.
+    /// shares they generate. Iterating over them yields the participants in
+    /// ascending order of their identifiers.
     pub(crate) verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
     /// The joint public key for the entire group.
     pub(crate) verifying_key: VerifyingKey<C>,
@@ -798,8 +1198,81 @@ where
         let group_commitment = sum_commitments(&commitments)?;
         Self::from_commitment(&identifiers, &group_commitment)
     }
+
+    /// Get the verifying share of a participant by their identifier, or None
+    /// if they are not part of the group.
+    pub fn verifying_share(&self, identifier: &Identifier<C>) -> Option<&VerifyingShare<C>> {
+        self.verifying_shares.get(identifier)
+    }
+
+    /// Returns the (identifier, verifying share) pair of each participant, in
+    /// ascending order of identifiers.
+    ///
+    /// Together with the [`VerifyingKey`] returned by
+    /// [`PublicKeyPackage::verifying_key`], this is all the package contains,
+    /// e.g. to load it into another store; use
+    /// [`PublicKeyPackage::from_verification_tuples`] to recreate it.
+    pub fn verification_tuples(&self) -> Vec<(Identifier<C>, VerifyingShare<C>)> {
+        self.verifying_shares
+            .iter()
+            .map(|(identifier, verifying_share)| (*identifier, *verifying_share))
+            .collect()
+    }
+
+    /// Creates a [`PublicKeyPackage`] from the (identifier, verifying share)
+    /// pairs of the participants, in any order, and the group
+    /// [`VerifyingKey`]. This is the inverse of
+    /// [`PublicKeyPackage::verification_tuples`].
+    ///
+    /// Returns [`Error::DuplicatedIdentifier`] if an identifier appears more
+    /// than once.
+    pub fn from_verification_tuples(
+        verification_tuples: impl IntoIterator<Item = (Identifier<C>, VerifyingShare<C>)>,
+        verifying_key: VerifyingKey<C>,
+    ) -> Result<Self, Error<C>> {
+        let mut verifying_shares = BTreeMap::new();
+        for (identifier, verifying_share) in verification_tuples {
+            if verifying_shares
+                .insert(identifier, verifying_share)
+                .is_some()
+            {
+                return Err(Error::DuplicatedIdentifier { identifier });
+            }
+        }
+        Ok(Self::new(verifying_shares, verifying_key))
+    }
+
+    /// Computes a short, stable identifier of the group, e.g. to label logs,
+    /// configuration or UIs when managing many groups.
+    ///
+    /// It is a truncated hash of the verifying key and of the verifying shares
+    /// in ascending order of identifiers, so it only depends on the contents
+    /// of the package, and any difference in them changes it. Since it is
+    /// truncated, it is meant for labeling and not to authenticate the group;
+    /// compare the full packages for that. If the ciphersuite's hash output is
+    /// shorter than 16 bytes, the remaining bytes are zero.
+    pub fn group_id(&self) -> [u8; 16] {
+        let mut preimage = vec![];
+        preimage.extend_from_slice(GROUP_ID_DOMAIN);
+        preimage.extend_from_slice(self.verifying_key.serialize().as_ref());
+        for (identifier, verifying_share) in &self.verifying_shares {
+            preimage.extend_from_slice(identifier.serialize().as_ref());
+            preimage.extend_from_slice(verifying_share.serialize().as_ref());
+        }
+
+        // Ciphersuites with a hash output shorter than 16 bytes leave the
+        // remaining bytes zero.
+        let mut group_id = [0u8; 16];
+        for (byte, hash_byte) in group_id.iter_mut().zip(C::H4(&preimage).as_ref()) {
+            *byte = *hash_byte;
+        }
+        group_id
+    }
 }
 
+/// The domain separator for [`PublicKeyPackage::group_id`].
+const GROUP_ID_DOMAIN: &[u8] = b"group id";
+
 #[cfg(feature = "serialization")]
 impl<C> PublicKeyPackage<C>
 where
@@ -897,13 +1370,10 @@ pub(crate) fn generate_secret_shares<C: Ciphersuite>(
 ) -> Result<Vec<SecretShare<C>>, Error<C>> {
     let mut secret_shares: Vec<SecretShare<C>> = Vec::with_capacity(max_signers as usize);
 
-    let (coefficients, commitment) =
-        generate_secret_polynomial(secret, max_signers, min_signers, coefficients)?;
+    Identifier::check_unique(identifiers)?;
 
-    let identifiers_set: HashSet<_> = identifiers.iter().collect();
-    if identifiers_set.len() != identifiers.len() {
-        return Err(Error::DuplicatedIdentifier);
-    }
+    let (mut coefficients, commitment) =
+        generate_secret_polynomial(secret, max_signers, min_signers, coefficients)?;
 
     for id in identifiers {
         let signing_share = SigningShare::from_coefficients(&coefficients, *id);
@@ -916,6 +1386,12 @@ pub(crate) fn generate_secret_shares<C: Ciphersuite>(
         });
     }
 
+    // The coefficients define the secret polynomial; wipe them now that the
+    // shares have been computed.
+    for c in coefficients.iter_mut() {
+        *c = <<C::Group as Group>::Field>::zero();
+    }
+
     Ok(secret_shares)
 }
 
@@ -952,15 +1428,9 @@ pub fn reconstruct<C: Ciphersuite>(
 
     let mut secret = <<C::Group as Group>::Field>::zero();
 
-    let identifiers: BTreeSet<_> = key_packages
-        .iter()
-        .map(|s| s.identifier())
-        .cloned()
-        .collect();
-
-    if identifiers.len() != key_packages.len() {
-        return Err(Error::DuplicatedIdentifier);
-    }
+    let identifiers: Vec<_> = key_packages.iter().map(|k| k.identifier).collect();
+    Identifier::check_unique(&identifiers)?;
+    let identifiers: BTreeSet<_> = identifiers.into_iter().collect();
 
     // Compute the Lagrange coefficients
     for key_package in key_packages.iter() {
@@ -973,3 +1443,186 @@ pub fn reconstruct<C: Ciphersuite>(
 
     Ok(SigningKey { scalar: secret })
 }
+
+/// Recompute the secret from at least `min_signers` [`SecretShare`]s using
+/// Lagrange interpolation.
+///
+/// Unlike [`reconstruct`], the threshold is inferred from the length of the
+/// shares' commitment, so that too few shares are detected. Each share is
+/// verified against its commitment, and all shares must have the same
+/// commitment; otherwise [`Error::MismatchedCommitments`] is returned, since
+/// the shares would not be points of the same polynomial. Duplicated
+/// identifiers return [`Error::DuplicatedIdentifier`].
+///
+/// This can be used e.g. by disaster recovery tooling where a quorum rebuilds
+/// the group signing key offline. Like [`reconstruct`], it is NOT required to
+/// sign with FROST.
+pub fn reconstruct_secret<C: Ciphersuite>(
+    secret_shares: &[SecretShare<C>],
+) -> Result<SigningKey<C>, Error<C>> {
+    let commitment = &secret_shares
+        .first()
+        .ok_or(Error::IncorrectNumberOfShares)?
+        .commitment;
+    if secret_shares.len() < commitment.coefficients().len() {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+    if secret_shares.iter().any(|s| s.commitment != *commitment) {
+        return Err(Error::MismatchedCommitments);
+    }
+
+    let identifiers: Vec<_> = secret_shares.iter().map(|s| s.identifier).collect();
+    Identifier::check_unique(&identifiers)?;
+    let identifiers: BTreeSet<_> = identifiers.into_iter().collect();
+
+    let mut secret = <<C::Group as Group>::Field>::zero();
+
+    for secret_share in secret_shares {
+        secret_share.verify()?;
+
+        let lagrange_coefficient =
+            compute_lagrange_coefficient(&identifiers, None, secret_share.identifier)?;
+
+        // Compute y = f(0) via polynomial interpolation of these t-of-n solutions ('points) of f
+        secret = secret + (lagrange_coefficient * secret_share.signing_share.0);
+    }
+
+    Ok(SigningKey { scalar: secret })
+}
+
+/// Refreshes the [`SecretShare`]s of a group without changing its
+/// [`VerifyingKey`], so that shares leaked before the refresh become useless.
+///
+/// A fresh sharing of zero with the same threshold is dealt and added to each
+/// share, and its commitment is added to the shares' commitment. This must be
+/// run by a dealer holding the shares of *all* participants: shares that are
+/// not refreshed can't be used together with the refreshed ones.
+///
+/// The threshold is inferred from the shares' commitment. Each share is
+/// verified before being refreshed; shares with different commitments return
+/// [`Error::MismatchedCommitments`], duplicated identifiers return
+/// [`Error::DuplicatedIdentifier`], and fewer shares than the threshold return
+/// [`Error::IncorrectNumberOfShares`].
+pub fn refresh_shares<C: Ciphersuite, R: RngCore + CryptoRng>(
+    secret_shares: &[SecretShare<C>],
+    rng: &mut R,
+) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+    let commitment = &secret_shares
+        .first()
+        .ok_or(Error::IncorrectNumberOfShares)?
+        .commitment;
+    if secret_shares.iter().any(|s| s.commitment != *commitment) {
+        return Err(Error::MismatchedCommitments);
+    }
+    let min_signers =
+        u16::try_from(commitment.coefficients().len()).map_err(|_| Error::InvalidMinSigners)?;
+    let max_signers =
+        u16::try_from(secret_shares.len()).map_err(|_| Error::IncorrectNumberOfShares)?;
+    if max_signers < min_signers {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+
+    let identifiers: Vec<_> = secret_shares.iter().map(|s| s.identifier).collect();
+    Identifier::check_unique(&identifiers)?;
+    for secret_share in secret_shares {
+        secret_share.verify()?;
+    }
+
+    let zero = SigningKey {
+        scalar: <<C::Group as Group>::Field>::zero(),
+    };
+    let (mut zero_coefficients, zero_commitment) = generate_secret_polynomial(
+        &zero,
+        max_signers,
+        min_signers,
+        generate_coefficients::<C, R>(min_signers as usize - 1, rng),
+    )?;
+    // The sharing must be of zero, otherwise the group key would change.
+    if zero_commitment.verifying_key()?.element != <C::Group>::identity() {
+        return Err(Error::MismatchedGroupPublicKey);
+    }
+    let refreshed_commitment = sum_commitments(&[commitment, &zero_commitment])?;
+
+    let refreshed_shares = secret_shares
+        .iter()
+        .map(|secret_share| SecretShare {
+            header: Header::default(),
+            identifier: secret_share.identifier,
+            signing_share: SigningShare(
+                secret_share.signing_share.0
+                    + SigningShare::from_coefficients(&zero_coefficients, secret_share.identifier)
+                        .0,
+            ),
+            commitment: refreshed_commitment.clone(),
+        })
+        .collect();
+
+    for c in zero_coefficients.iter_mut() {
+        *c = <<C::Group as Group>::Field>::zero();
+    }
+
+    Ok(index_secret_shares(
+        refreshed_shares,
+        commitment.verifying_key()?,
+    ))
+}
+
+/// Produces a signature with key packages that are all held by the caller,
+/// e.g. a custodial service holding at least `min_signers` shares, by running
+/// both rounds for each of them and aggregating the signature shares.
+///
+/// Fresh, independent nonces are generated for each share from `rng`.
+/// Returns [`Error::IncorrectNumberOfShares`] if there are fewer key packages
+/// than `min_signers`, [`Error::DuplicatedIdentifier`] if a participant is
+/// repeated and [`Error::MismatchedGroupPublicKey`] if the key packages don't
+/// belong to the same group.
+pub fn sign_custodial<C: Ciphersuite, R: RngCore + CryptoRng>(
+    key_packages: &[KeyPackage<C>],
+    message: &[u8],
+    mut rng: R,
+) -> Result<crate::Signature<C>, Error<C>> {
+    let first = key_packages.first().ok_or(Error::IncorrectNumberOfShares)?;
+    if key_packages.len() < first.min_signers as usize {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+    if key_packages
+        .iter()
+        .any(|k| k.verifying_key != first.verifying_key)
+    {
+        return Err(Error::MismatchedGroupPublicKey);
+    }
+    let identifiers: Vec<_> = key_packages.iter().map(|k| k.identifier).collect();
+    Identifier::check_unique(&identifiers)?;
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for key_package in key_packages {
+        let (signer_nonces, signer_commitments) =
+            crate::round1::commit(&key_package.signing_share, &mut rng);
+        nonces.insert(key_package.identifier, signer_nonces);
+        commitments.insert(key_package.identifier, signer_commitments);
+    }
+    let signing_package = crate::SigningPackage::new(commitments, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for key_package in key_packages {
+        let signer_nonces =
+            nonces
+                .get(&key_package.identifier)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: key_package.identifier,
+                })?;
+        let signature_share = crate::round2::sign(&signing_package, signer_nonces, key_package)?;
+        signature_shares.insert(key_package.identifier, signature_share);
+    }
+
+    let pubkeys = PublicKeyPackage::new(
+        key_packages
+            .iter()
+            .map(|k| (k.identifier, k.verifying_share))
+            .collect(),
+        first.verifying_key,
+    );
+
+    crate::aggregate(&signing_package, &signature_shares, &pubkeys)
+}
@@ -48,7 +48,7 @@ use super::{
 /// DKG Round 1 structures.
 pub mod round1 {
     use derive_getters::Getters;
-    use zeroize::Zeroize;
+    use zeroize::{Zeroize, ZeroizeOnDrop};
 
     use crate::serialization::{Deserialize, Serialize};
 
@@ -160,12 +160,23 @@ pub mod round1 {
             }
         }
     }
+
+    impl<C> Drop for SecretPackage<C>
+    where
+        C: Ciphersuite,
+    {
+        fn drop(&mut self) {
+            self.zeroize()
+        }
+    }
+
+    impl<C> ZeroizeOnDrop for SecretPackage<C> where C: Ciphersuite {}
 }
 
 /// DKG Round 2 structures.
 pub mod round2 {
     use derive_getters::Getters;
-    use zeroize::Zeroize;
+    use zeroize::{Zeroize, ZeroizeOnDrop};
 
     use crate::serialization::{Deserialize, Serialize};
 
@@ -262,6 +273,17 @@ pub mod round2 {
             self.secret_share = <<C::Group as Group>::Field>::zero();
         }
     }
+
+    impl<C> Drop for SecretPackage<C>
+    where
+        C: Ciphersuite,
+    {
+        fn drop(&mut self) {
+            self.zeroize()
+        }
+    }
+
+    impl<C> ZeroizeOnDrop for SecretPackage<C> where C: Ciphersuite {}
 }
 
 /// Performs the first part of the distributed key generation protocol
@@ -410,6 +432,11 @@ pub fn part2<C: Ciphersuite>(
 
     for (sender_identifier, round1_package) in round1_packages {
         let ell = *sender_identifier;
+        // The commitment must be to a polynomial of the agreed degree;
+        // otherwise the sender would change the threshold of the group key.
+        round1_package
+            .commitment
+            .check_min_signers(secret_package.min_signers)?;
         // Round 1, Step 5
         verify_proof_of_knowledge(
             ell,
@@ -436,7 +463,7 @@ pub fn part2<C: Ciphersuite>(
     Ok((
         round2::SecretPackage {
             identifier: secret_package.identifier,
-            commitment: secret_package.commitment,
+            commitment: secret_package.commitment.clone(),
             secret_share: fii,
             min_signers: secret_package.min_signers,
             max_signers: secret_package.max_signers,
@@ -495,6 +522,7 @@ pub fn part3<C: Ciphersuite>(
             .get(&ell)
             .ok_or(Error::PackageNotFound)?
             .commitment;
+        commitment.check_min_signers(round2_secret_package.min_signers)?;
 
         // The verification is exactly the same as the regular SecretShare verification;
         // however the required components are in different places.
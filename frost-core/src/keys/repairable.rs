@@ -33,10 +33,8 @@ pub fn repair_share_step_1<C: Ciphersuite, R: RngCore + CryptoRng>(
     if helpers.is_empty() {
         return Err(Error::IncorrectNumberOfIdentifiers);
     }
+    Identifier::check_unique(helpers)?;
     let xset: BTreeSet<_> = helpers.iter().cloned().collect();
-    if xset.len() != helpers.len() {
-        return Err(Error::DuplicatedIdentifier);
-    }
 
     let rand_val: Vec<Scalar<C>> = generate_coefficients::<C, R>(helpers.len() - 1, rng);
 
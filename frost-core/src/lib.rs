@@ -26,9 +26,14 @@ use zeroize::Zeroize;
 pub mod batch;
 #[cfg(any(test, feature = "test-impl"))]
 pub mod benches;
+#[cfg(feature = "serde")]
+pub mod codec;
+pub mod decrypt;
+pub mod designated;
 mod error;
 mod identifier;
 pub mod keys;
+pub mod replay;
 pub mod round1;
 pub mod round2;
 mod scalar_mul;
@@ -52,7 +57,7 @@ pub use serde;
 pub use signature::Signature;
 pub use signing_key::SigningKey;
 pub use traits::{Ciphersuite, Element, Field, Group, Scalar};
-pub use verifying_key::VerifyingKey;
+pub use verifying_key::{SignatureFormat, VerifyingKey};
 
 /// A type refinement for the scalar field element representing the per-message _[challenge]_.
 ///
@@ -101,7 +106,8 @@ where
 /// Deals in bytes, so that [FROST] and singleton signing and verification can use it with different
 /// types.
 ///
-/// This is the only invocation of the H2 hash function from the [RFC].
+/// This is the only invocation of the H2 hash function from the [RFC], apart
+/// from the challenge of [`designated`] signatures.
 ///
 /// [FROST]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-signature-challenge-computa
 /// [RFC]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-3.2
@@ -111,13 +117,33 @@ fn challenge<C>(R: &Element<C>, verifying_key: &VerifyingKey<C>, msg: &[u8]) ->
 where
     C: Ciphersuite,
 {
+    let preimage = challenge_preimage(R, verifying_key, msg);
+
+    Challenge(C::H2(&preimage[..]))
+}
+
+/// Returns the exact bytes that are hashed with H2 to compute the challenge of
+/// a signature with group commitment `group_commitment` (the `R` of the
+/// signature) under `verifying_key` on `msg`.
+///
+/// The preimage is the serialized group commitment, followed by the serialized
+/// verifying key and the message, as [specified][FROST]. This allows
+/// integrators of external verifiers (e.g. smart contracts) that recompute the
+/// challenge to compare their preimage with this one byte-by-byte.
+///
+/// [FROST]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-signature-challenge-computa
+pub fn challenge_preimage<C: Ciphersuite>(
+    group_commitment: &Element<C>,
+    verifying_key: &VerifyingKey<C>,
+    msg: &[u8],
+) -> Vec<u8> {
     let mut preimage = vec![];
 
-    preimage.extend_from_slice(<C::Group>::serialize(R).as_ref());
+    preimage.extend_from_slice(<C::Group>::serialize(group_commitment).as_ref());
     preimage.extend_from_slice(<C::Group>::serialize(&verifying_key.element).as_ref());
     preimage.extend_from_slice(msg);
 
-    Challenge(C::H2(&preimage[..]))
+    preimage
 }
 
 /// Generates a random nonzero scalar.
@@ -311,13 +337,12 @@ fn compute_lagrange_coefficient<C: Ciphersuite>(
         }
     }
     if !x_i_found {
-        return Err(Error::UnknownIdentifier);
+        return Err(Error::UnknownIdentifier { identifier: x_i });
     }
 
-    Ok(
-        num * <<C::Group as Group>::Field>::invert(&den)
-            .map_err(|_| Error::DuplicatedIdentifier)?,
-    )
+    Ok(num
+        * <<C::Group as Group>::Field>::invert(&den)
+            .map_err(|_| Error::DuplicatedIdentifier { identifier: x_i })?)
 }
 
 /// Generates the lagrange coefficient for the i'th participant (for `signer_id`).
@@ -342,6 +367,40 @@ fn derive_interpolating_value<C: Ciphersuite>(
     )
 }
 
+/// The domain separator of challenges bound to a network id.
+const NETWORK_ID_DOMAIN: &[u8] = b"FROST-network-id";
+
+/// Generates the challenge of a signature bound to `network_id`, as signed
+/// with a [`SigningPackage`] created with [`SigningPackage::new_with_network_id`].
+///
+/// The H2 preimage of [`challenge`] is prefixed with a domain separator and
+/// the length-prefixed `network_id`. Since the preimage of a regular challenge
+/// starts with the group commitment instead, no message can make a regular
+/// signature verify under a network id. An empty `network_id` doesn't bind
+/// the signature to any network, and gives the regular challenge.
+pub(crate) fn challenge_with_network_id<C>(
+    R: &Element<C>,
+    verifying_key: &VerifyingKey<C>,
+    network_id: &[u8],
+    msg: &[u8],
+) -> Challenge<C>
+where
+    C: Ciphersuite,
+{
+    if network_id.is_empty() {
+        return challenge(R, verifying_key, msg);
+    }
+
+    let mut preimage = vec![];
+
+    preimage.extend_from_slice(NETWORK_ID_DOMAIN);
+    preimage.extend_from_slice(&(network_id.len() as u64).to_be_bytes());
+    preimage.extend_from_slice(network_id);
+    preimage.extend_from_slice(&challenge_preimage(R, verifying_key, msg));
+
+    Challenge(C::H2(&preimage[..]))
+}
+
 /// Generated by the coordinator of the signing operation and distributed to
 /// each signing party
 #[derive(Clone, Debug, PartialEq, Eq, Getters)]
@@ -367,6 +426,17 @@ pub struct SigningPackage<C: Ciphersuite> {
         )
     )]
     message: Vec<u8>,
+    /// The network id the signature is bound to, or empty if it isn't bound
+    /// to any. See [`SigningPackage::new_with_network_id`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            serialize_with = "serdect::slice::serialize_hex_lower_or_bin",
+            deserialize_with = "serdect::slice::deserialize_hex_or_bin_vec"
+        )
+    )]
+    network_id: Vec<u8>,
 }
 
 impl<C> SigningPackage<C>
@@ -384,7 +454,171 @@ where
             header: Header::default(),
             signing_commitments,
             message: message.to_vec(),
+            network_id: vec![],
+        }
+    }
+
+    /// Create a new `SigningPackage`, first checking that every commitment is
+    /// a canonically encoded, non-identity group element, and that no two
+    /// participants sent the same commitment.
+    ///
+    /// Coordinators should use this when assembling commitments received from
+    /// the network, so that an invalid commitment is attributed to its sender
+    /// before the package is distributed to the signers. Returns
+    /// [`Error::InvalidCommitment`] naming the participant with the first
+    /// invalid commitment, or [`Error::SuspiciousCommitments`] if a commitment
+    /// appears for two participants.
+    pub fn new_checked(
+        signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
+        message: &[u8],
+    ) -> Result<SigningPackage<C>, Error<C>> {
+        for (identifier, commitments) in &signing_commitments {
+            for commitment in [&commitments.hiding, &commitments.binding] {
+                let valid = commitment.0 != <C::Group>::identity()
+                    && <C::Group>::deserialize(&<C::Group>::serialize(&commitment.0))
+                        .map_or(false, |element| element == commitment.0);
+                if !valid {
+                    return Err(Error::InvalidCommitment {
+                        culprit: *identifier,
+                    });
+                }
+            }
+        }
+
+        let signing_package = Self::new(signing_commitments, message);
+        signing_package.check_distinct_commitments()?;
+
+        Ok(signing_package)
+    }
+
+    /// Checks that no nonce commitment appears for two distinct participants,
+    /// returning [`Error::SuspiciousCommitments`] otherwise.
+    ///
+    /// Distinct signers sharing a nonce is catastrophic, since it can leak
+    /// their signing shares, so this almost certainly signals a bug or leaked
+    /// nonces.
+    pub(crate) fn check_distinct_commitments(&self) -> Result<(), Error<C>> {
+        let mut seen = BTreeMap::new();
+        for (identifier, commitments) in &self.signing_commitments {
+            for commitment in [&commitments.hiding, &commitments.binding] {
+                let bytes = <C::Group>::serialize(&commitment.0).as_ref().to_vec();
+                if let Some(other) = seen.insert(bytes, *identifier) {
+                    if other != *identifier {
+                        return Err(Error::SuspiciousCommitments);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create a new `SigningPackage` whose signature is bound to the given
+    /// `network_id`, e.g. a chain id, to prevent replaying it on other
+    /// networks.
+    ///
+    /// The network id is folded into the challenge computed by
+    /// [`round2::sign`] and [`aggregate`], so that signatures for the same
+    /// `message` with different network ids don't cross-verify. The resulting
+    /// signature must be verified with [`VerifyingKey::verify_with_network_id`].
+    /// An empty `network_id` doesn't bind the signature to any network.
+    pub fn new_with_network_id(
+        signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
+        network_id: &[u8],
+        message: &[u8],
+    ) -> SigningPackage<C> {
+        SigningPackage {
+            network_id: network_id.to_vec(),
+            ..Self::new(signing_commitments, message)
+        }
+    }
+
+    /// Computes the challenge of the signature with the given group
+    /// commitment, bound to the network id of this signing package, if any.
+    pub(crate) fn challenge(
+        &self,
+        group_commitment: &GroupCommitment<C>,
+        verifying_key: &VerifyingKey<C>,
+    ) -> Challenge<C> {
+        challenge_with_network_id(
+            &group_commitment.0,
+            verifying_key,
+            &self.network_id,
+            &self.message,
+        )
+    }
+
+    /// Verifies a `signature` over the message of this signing package,
+    /// bound to its network id, if any.
+    pub(crate) fn verify_signature(
+        &self,
+        verifying_key: &VerifyingKey<C>,
+        signature: &Signature<C>,
+    ) -> Result<(), Error<C>> {
+        verifying_key.verify_with_network_id(&self.network_id, &self.message, signature)
+    }
+
+    /// Checks that every participant with a commitment in this
+    /// [`SigningPackage`] is a member of the group described by `pubkeys`,
+    /// returning [`Error::UnknownIdentifier`] naming the first one that isn't
+    /// otherwise.
+    ///
+    /// A stray identifier (e.g. a typo or a stale configuration of the
+    /// coordinator) would otherwise only be detected late, or produce an
+    /// invalid signature; signers and coordinators that have the
+    /// [`keys::PublicKeyPackage`] can call this before signing or aggregating.
+    pub fn check_against_public_package(
+        &self,
+        pubkeys: &keys::PublicKeyPackage<C>,
+    ) -> Result<(), Error<C>> {
+        match self
+            .signing_commitments
+            .keys()
+            .find(|identifier| !pubkeys.verifying_shares.contains_key(identifier))
+        {
+            Some(identifier) => Err(Error::UnknownIdentifier {
+                identifier: *identifier,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that the number of commitments in this [`SigningPackage`] is
+    /// between `min_signers` and `max_signers`, inclusive.
+    ///
+    /// Returns [`Error::TooFewCommitments`] or [`Error::TooManyCommitments`]
+    /// with the actual and expected counts otherwise. Key packages don't
+    /// record `max_signers`; it can be taken from the number of verifying
+    /// shares of the group's [`keys::PublicKeyPackage`].
+    pub fn check_num_commitments(
+        &self,
+        min_signers: u16,
+        max_signers: u16,
+    ) -> Result<(), Error<C>> {
+        self.check_min_commitments(min_signers)?;
+        let have = self.signing_commitments.len();
+        if have > max_signers as usize {
+            return Err(Error::TooManyCommitments {
+                have,
+                max: max_signers as usize,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that there are at least `min_signers` commitments in this
+    /// [`SigningPackage`], returning [`Error::TooFewCommitments`] otherwise.
+    pub(crate) fn check_min_commitments(&self, min_signers: u16) -> Result<(), Error<C>> {
+        let have = self.signing_commitments.len();
+        if have < min_signers as usize {
+            return Err(Error::TooFewCommitments {
+                have,
+                need: min_signers as usize,
+            });
         }
+
+        Ok(())
     }
 
     /// Get a signing commitment by its participant identifier, or None if not found.
@@ -395,6 +629,39 @@ where
         self.signing_commitments.get(identifier).copied()
     }
 
+    /// Computes a digest of the message and the commitments of the signing
+    /// package, to be sent by each signer along with their signature share so
+    /// that [`aggregate_with_signing_package_digests`] can check that they
+    /// signed the same package as the coordinator.
+    pub fn digest(&self) -> Vec<u8> {
+        let mut preimage = vec![];
+        preimage.extend_from_slice(SIGNING_PACKAGE_DIGEST_DOMAIN);
+        // Both are hashed to a fixed length, like in the binding factor
+        // preimages, so the concatenation is unambiguous.
+        preimage.extend_from_slice(C::H4(self.message.as_slice()).as_ref());
+        preimage.extend_from_slice(
+            C::H5(&round1::encode_group_commitments(self.signing_commitments())[..]).as_ref(),
+        );
+        preimage.extend_from_slice(C::H4(self.network_id.as_slice()).as_ref());
+
+        C::H4(&preimage).as_ref().to_vec()
+    }
+
+    /// Computes the [`GroupCommitment`] of the signing package for the group
+    /// with the given verifying key, which is the commitment `R` of the
+    /// signature produced from it.
+    ///
+    /// This allows linking a signature back to the signing package it was
+    /// produced from, with [`GroupCommitment::matches_signature`].
+    pub fn group_commitment(
+        &self,
+        verifying_key: &VerifyingKey<C>,
+    ) -> Result<GroupCommitment<C>, Error<C>> {
+        let (group_commitment, _) =
+            compute_group_commitment_and_binding_factors(self, verifying_key, &[])?;
+        Ok(group_commitment)
+    }
+
     /// Compute the preimages to H1 to compute the per-signer binding factors
     // We separate this out into its own method so it can be tested
     #[cfg_attr(feature = "internals", visibility::make(pub))]
@@ -433,6 +700,9 @@ where
     }
 }
 
+/// The domain separator for [`SigningPackage::digest`].
+const SIGNING_PACKAGE_DIGEST_DOMAIN: &[u8] = b"signing package";
+
 #[cfg(feature = "serialization")]
 impl<C> SigningPackage<C>
 where
@@ -449,12 +719,91 @@ where
     }
 }
 
+/// Accumulates the [`round1::SigningCommitments`] of the participants into a
+/// [`SigningPackage`], for coordinators that receive them one at a time and in
+/// any order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningPackageBuilder<C: Ciphersuite> {
+    /// The minimum number of commitments required to build the package.
+    min_signers: u16,
+    /// The commitments received so far.
+    signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
+    /// The message to sign, if set.
+    message: Option<Vec<u8>>,
+}
+
+impl<C> SigningPackageBuilder<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new, empty [`SigningPackageBuilder`] for a group with the given
+    /// `min_signers`.
+    pub fn new(min_signers: u16) -> Self {
+        Self {
+            min_signers,
+            signing_commitments: BTreeMap::new(),
+            message: None,
+        }
+    }
+
+    /// Adds the commitments of the participant `identifier`.
+    ///
+    /// Returns [`Error::DuplicatedIdentifier`] if commitments were already
+    /// added for that participant, leaving the builder unchanged.
+    pub fn add_commitment(
+        &mut self,
+        identifier: Identifier<C>,
+        commitments: round1::SigningCommitments<C>,
+    ) -> Result<(), Error<C>> {
+        if self.signing_commitments.contains_key(&identifier) {
+            return Err(Error::DuplicatedIdentifier { identifier });
+        }
+        self.signing_commitments.insert(identifier, commitments);
+        Ok(())
+    }
+
+    /// Sets the message to sign, replacing any previously set message.
+    pub fn set_message(&mut self, message: &[u8]) {
+        self.message = Some(message.to_vec());
+    }
+
+    /// Returns the number of participants whose commitments were added.
+    pub fn len(&self) -> usize {
+        self.signing_commitments.len()
+    }
+
+    /// Returns true if no commitments were added yet.
+    pub fn is_empty(&self) -> bool {
+        self.signing_commitments.is_empty()
+    }
+
+    /// Returns true if [`SigningPackageBuilder::build`] would succeed, i.e. if
+    /// the message is set and at least `min_signers` commitments were added.
+    pub fn is_complete(&self) -> bool {
+        self.message.is_some() && self.len() >= self.min_signers as usize
+    }
+
+    /// Builds the [`SigningPackage`].
+    ///
+    /// Returns [`Error::MissingMessage`] if the message was not set, and
+    /// [`Error::TooFewCommitments`] if fewer than `min_signers` commitments
+    /// were added.
+    pub fn build(&self) -> Result<SigningPackage<C>, Error<C>> {
+        let message = self.message.as_ref().ok_or(Error::MissingMessage)?;
+        let signing_package = SigningPackage::new(self.signing_commitments.clone(), message);
+        signing_package.check_min_commitments(self.min_signers)?;
+
+        Ok(signing_package)
+    }
+}
+
 /// The product of all signers' individual commitments, published as part of the
 /// final signature.
+///
+/// It can be computed from a [`SigningPackage`] with
+/// [`SigningPackage::group_commitment`].
 #[derive(Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "internals", visibility::make(pub))]
-#[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
-pub(crate) struct GroupCommitment<C: Ciphersuite>(pub(crate) Element<C>);
+pub struct GroupCommitment<C: Ciphersuite>(pub(crate) Element<C>);
 
 impl<C> GroupCommitment<C>
 where
@@ -465,23 +814,45 @@ where
     pub fn to_element(self) -> <C::Group as Group>::Element {
         self.0
     }
+
+    /// Return whether this group commitment is the commitment `R` of the
+    /// given signature, i.e. whether the signature was produced from the
+    /// signing commitments this group commitment was computed from.
+    pub fn matches_signature(&self, signature: &Signature<C>) -> bool {
+        self.0 == signature.R
+    }
 }
 
-/// Generates the group commitment which is published as part of the joint
-/// Schnorr signature.
+impl<C> Debug for GroupCommitment<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("GroupCommitment")
+            .field(&hex::encode(<C::Group>::serialize(&self.0)))
+            .finish()
+    }
+}
+
+/// Computes the binding factors with [`compute_binding_factor_list`], and the
+/// group commitment which is published as part of the joint Schnorr signature.
 ///
 /// Implements [`compute_group_commitment`] from the spec.
 ///
 /// [`compute_group_commitment`]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-4.5
 #[cfg_attr(feature = "internals", visibility::make(pub))]
 #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
-fn compute_group_commitment<C>(
+fn compute_group_commitment_and_binding_factors<C>(
     signing_package: &SigningPackage<C>,
-    binding_factor_list: &BindingFactorList<C>,
-) -> Result<GroupCommitment<C>, Error<C>>
+    verifying_key: &VerifyingKey<C>,
+    additional_prefix: &[u8],
+) -> Result<(GroupCommitment<C>, BindingFactorList<C>), Error<C>>
 where
     C: Ciphersuite,
 {
+    let binding_factor_list =
+        compute_binding_factor_list(signing_package, verifying_key, additional_prefix);
+
     let identity = <C::Group as Group>::identity();
 
     let mut group_commitment = <C::Group as Group>::identity();
@@ -493,17 +864,19 @@ where
 
     let mut binding_elements = Vec::with_capacity(n);
 
-    for (commitment_identifier, commitment) in signing_package.signing_commitments() {
+    // Both the binding factors and the commitments are sorted by identifier,
+    // so they can be zipped together.
+    for (commitment, binding_factor) in signing_package
+        .signing_commitments()
+        .values()
+        .zip(binding_factor_list.0.values())
+    {
         // The following check prevents a party from accidentally revealing their share.
         // Note that the '&&' operator would be sufficient.
         if identity == commitment.binding.0 || identity == commitment.hiding.0 {
             return Err(Error::IdentityCommitment);
         }
 
-        let binding_factor = binding_factor_list
-            .get(commitment_identifier)
-            .ok_or(Error::UnknownIdentifier)?;
-
         // Collect the binding commitments and their binding factors for one big
         // multiscalar multiplication at the end.
         binding_elements.push(commitment.binding.0);
@@ -517,13 +890,87 @@ where
 
     group_commitment = group_commitment + accumulated_binding_commitment;
 
-    Ok(GroupCommitment(group_commitment))
+    Ok((GroupCommitment(group_commitment), binding_factor_list))
+}
+
+/// Randomly selects `size` participants from the given [`keys::PublicKeyPackage`]
+/// to sign, e.g. when the group is too large to gather commitments from
+/// every participant.
+///
+/// Each subset of `size` participants is selected with the same probability.
+/// The coordinator then runs a regular signing round with the returned
+/// participants, which are sorted by identifier.
+///
+/// Returns an error if `size` is smaller than `min_signers` or larger than the
+/// number of participants in the group.
+pub fn select_committee<C: Ciphersuite, R: RngCore + CryptoRng>(
+    pubkeys: &keys::PublicKeyPackage<C>,
+    min_signers: u16,
+    size: u16,
+    mut rng: R,
+) -> Result<Vec<Identifier<C>>, Error<C>> {
+    let mut identifiers: Vec<Identifier<C>> = pubkeys.verifying_shares.keys().copied().collect();
+    let size = size as usize;
+    if size < min_signers as usize || size > identifiers.len() {
+        return Err(Error::IncorrectNumberOfIdentifiers);
+    }
+
+    // Partial Fisher-Yates shuffle: the first `size` identifiers are a
+    // uniformly random subset.
+    for i in 0..size {
+        let j = i + random_index(identifiers.len() - i, &mut rng);
+        identifiers.swap(i, j);
+    }
+    identifiers.truncate(size);
+    identifiers.sort();
+
+    Ok(identifiers)
+}
+
+/// Returns a uniformly random index in `0..n`, using rejection sampling to
+/// avoid modulo bias.
+fn random_index<R: RngCore + CryptoRng>(n: usize, rng: &mut R) -> usize {
+    let n = n as u64;
+    // The largest multiple of n that fits in a u64; values at or above it are
+    // rejected.
+    let zone = u64::MAX - (u64::MAX % n);
+    loop {
+        let v = rng.next_u64();
+        if v < zone {
+            return (v % n) as usize;
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // Aggregation
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Checks that `signature_shares` has a share for exactly the participants
+/// in the `signing_package`, returning [`Error::UnknownIdentifier`] naming the
+/// first identifier that is only in one of them otherwise.
+pub(crate) fn check_signature_shares_match<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+) -> Result<(), Error<C>> {
+    let signing_commitments = signing_package.signing_commitments();
+    let unmatched = signing_commitments
+        .keys()
+        .filter(|identifier| !signature_shares.contains_key(identifier))
+        .chain(
+            signature_shares
+                .keys()
+                .filter(|identifier| !signing_commitments.contains_key(identifier)),
+        )
+        .min();
+    match unmatched {
+        Some(identifier) => Err(Error::UnknownIdentifier {
+            identifier: *identifier,
+        }),
+        None => Ok(()),
+    }
+}
+
 /// Aggregates the signature shares to produce a final signature that
 /// can be verified with the group public key.
 ///
@@ -542,7 +989,15 @@ where
 /// signature, if the coordinator themselves is a signer and misbehaves, they
 /// can avoid that step. However, at worst, this results in a denial of
 /// service attack due to publishing an invalid signature.
-
+///
+/// The aggregation can also be performed by a party other than the
+/// coordinator, since it only requires the [`SigningPackage`], the signature
+/// shares and the [`keys::PublicKeyPackage`]; see [`Combiner`].
+///
+/// If the aggregate signature is invalid, every signature share is verified
+/// and [`Error::InvalidSignatureShares`] is returned naming all the
+/// participants whose share is invalid, so that they can be excluded from the
+/// next signing attempt at once.
 pub fn aggregate<C>(
     signing_package: &SigningPackage<C>,
     signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
@@ -553,25 +1008,14 @@ where
 {
     // Check if signing_package.signing_commitments and signature_shares have
     // the same set of identifiers, and if they are all in pubkeys.verifying_shares.
-    if signing_package.signing_commitments().len() != signature_shares.len() {
-        return Err(Error::UnknownIdentifier);
-    }
-    if !signing_package.signing_commitments().keys().all(|id| {
-        #[cfg(feature = "cheater-detection")]
-        return signature_shares.contains_key(id) && pubkeys.verifying_shares().contains_key(id);
-        #[cfg(not(feature = "cheater-detection"))]
-        return signature_shares.contains_key(id);
-    }) {
-        return Err(Error::UnknownIdentifier);
-    }
+    check_signature_shares_match(signing_package, signature_shares)?;
+    #[cfg(feature = "cheater-detection")]
+    signing_package.check_against_public_package(pubkeys)?;
 
     // Encodes the signing commitment list produced in round one as part of generating [`BindingFactor`], the
-    // binding factor.
-    let binding_factor_list: BindingFactorList<C> =
-        compute_binding_factor_list(signing_package, &pubkeys.verifying_key, &[]);
-
-    // Compute the group commitment from signing commitments produced in round one.
-    let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
+    // binding factor, and computes the group commitment from the same commitments.
+    let (group_commitment, binding_factor_list) =
+        compute_group_commitment_and_binding_factors(signing_package, &pubkeys.verifying_key, &[])?;
 
     // The aggregation of the signature shares by summing them up, resulting in
     // a plain Schnorr signature.
@@ -585,15 +1029,10 @@ where
         z = z + signature_share.share;
     }
 
-    let signature = Signature {
-        R: group_commitment.0,
-        z,
-    };
+    let signature = Signature::from_components(group_commitment.clone(), z);
 
     // Verify the aggregate signature
-    let verification_result = pubkeys
-        .verifying_key
-        .verify(signing_package.message(), &signature);
+    let verification_result = signing_package.verify_signature(&pubkeys.verifying_key, &signature);
 
     // Only if the verification of the aggregate signature failed; verify each share to find the cheater.
     // This approach is more efficient since we don't need to verify all shares
@@ -601,50 +1040,529 @@ where
     #[cfg(feature = "cheater-detection")]
     if let Err(err) = verification_result {
         // Compute the per-message challenge.
-        let challenge = crate::challenge::<C>(
-            &group_commitment.0,
-            &pubkeys.verifying_key,
-            signing_package.message().as_slice(),
-        );
+        let challenge = signing_package.challenge(&group_commitment, &pubkeys.verifying_key);
+        verify_signature_shares(
+            signing_package,
+            pubkeys,
+            signature_shares,
+            &binding_factor_list,
+            &challenge,
+        )?;
+
+        // All signature shares are valid, so the aggregate signature is
+        // invalid either because there are fewer signers than the threshold,
+        // or because the verifying shares don't interpolate to the group
+        // verifying key, e.g. if they were mixed from different groups. The
+        // threshold is unknown here, so check the latter by interpolating all
+        // the verifying shares in the public key package.
+        check_public_key_package_interpolates(pubkeys)?;
 
-        // Verify the signature shares.
-        for (signature_share_identifier, signature_share) in signature_shares {
-            // Look up the public key for this signer, where `signer_pubkey` = _G.ScalarBaseMult(s[i])_,
-            // and where s[i] is a secret share of the constant term of _f_, the secret polynomial.
-            let signer_pubkey = pubkeys
-                .verifying_shares
-                .get(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?;
+        // We should never reach here; but we return the verification error to be safe.
+        return Err(err);
+    }
 
-            // Compute Lagrange coefficient.
-            let lambda_i = derive_interpolating_value(signature_share_identifier, signing_package)?;
+    #[cfg(not(feature = "cheater-detection"))]
+    verification_result?;
 
-            let binding_factor = binding_factor_list
-                .get(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?;
+    Ok(signature)
+}
 
-            // Compute the commitment share.
-            let R_share = signing_package
-                .signing_commitment(signature_share_identifier)
-                .ok_or(Error::UnknownIdentifier)?
-                .to_group_commitment_share(binding_factor);
+/// Like [`aggregate`], but also cross-checks the share of the group
+/// commitment that each participant reports having used, computed with
+/// [`round2::group_commitment_share`], against their commitments in the
+/// signing package.
+///
+/// This detects a participant who signed with other nonces than the ones
+/// they committed to, returning [`Error::InconsistentCommitmentShare`] naming
+/// them, even without cheater detection. A participant with no reported
+/// commitment share is also blamed.
+pub fn aggregate_with_commitment_shares<C>(
+    signing_package: &SigningPackage<C>,
+    commitment_shares: &BTreeMap<Identifier<C>, round1::GroupCommitmentShare<C>>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<Signature<C>, Error<C>>
+where
+    C: Ciphersuite,
+{
+    let (_, binding_factor_list) =
+        compute_group_commitment_and_binding_factors(signing_package, &pubkeys.verifying_key, &[])?;
+
+    for (identifier, commitments) in signing_package.signing_commitments() {
+        let binding_factor =
+            binding_factor_list
+                .get(identifier)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: *identifier,
+                })?;
+        let expected = commitments.to_group_commitment_share(binding_factor);
+        if commitment_shares.get(identifier) != Some(&expected) {
+            return Err(Error::InconsistentCommitmentShare {
+                culprit: *identifier,
+            });
+        }
+    }
+
+    aggregate(signing_package, signature_shares, pubkeys)
+}
 
-            // Compute relation values to verify this signature share.
-            signature_share.verify(
+/// Like [`aggregate`], but first checks the digest of the signing package
+/// that each participant reports having signed, computed with
+/// [`SigningPackage::digest`], against the one being aggregated.
+///
+/// This detects shares computed over another signing package, e.g. with a
+/// different message, returning [`Error::SigningPackageMismatch`] naming the
+/// participant instead of failing with an invalid signature. A participant
+/// with a signature share but no reported digest is also blamed.
+pub fn aggregate_with_signing_package_digests<C>(
+    signing_package: &SigningPackage<C>,
+    signing_package_digests: &BTreeMap<Identifier<C>, Vec<u8>>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<Signature<C>, Error<C>>
+where
+    C: Ciphersuite,
+{
+    let expected = signing_package.digest();
+    for identifier in signature_shares.keys() {
+        if signing_package_digests.get(identifier) != Some(&expected) {
+            return Err(Error::SigningPackageMismatch {
+                culprit: *identifier,
+            });
+        }
+    }
+
+    aggregate(signing_package, signature_shares, pubkeys)
+}
+
+/// Aggregates signature shares received as serialized bytes.
+///
+/// Each share is deserialized with [`round2::SignatureShare::deserialize`]
+/// and the result is passed to [`aggregate`]. If the bytes sent by a
+/// participant can't be deserialized, an
+/// [`Error::MalformedSignatureShare`] naming that participant is returned.
+pub fn aggregate_from_bytes<C>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, Vec<u8>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<Signature<C>, Error<C>>
+where
+    C: Ciphersuite,
+{
+    let signature_shares = signature_shares
+        .iter()
+        .map(|(identifier, bytes)| {
+            let malformed = Error::MalformedSignatureShare {
+                culprit: *identifier,
+            };
+            let serialization = bytes.clone().try_into().map_err(|_| malformed.clone())?;
+            let signature_share =
+                round2::SignatureShare::deserialize(serialization).map_err(|_| malformed)?;
+            Ok((*identifier, signature_share))
+        })
+        .collect::<Result<BTreeMap<_, _>, Error<C>>>()?;
+
+    aggregate(signing_package, &signature_shares, pubkeys)
+}
+
+/// Aggregates signature shares pulled from an iterator, without collecting
+/// them.
+///
+/// Each share is added to a running sum as it is pulled from
+/// `signature_shares`, and the group commitment is computed in a single pass
+/// over the commitments in the `signing_package`, so that the full set of
+/// shares is never held in memory at once; this helps with large signing sets.
+///
+/// Each identifier must be in the `signing_package` and appear only once, and
+/// there must be a share for every participant in it; otherwise
+/// [`Error::UnknownIdentifier`] is returned.
+///
+/// Since the shares are not kept, they can't be verified individually if the
+/// resulting signature is invalid; [`Error::InvalidSignature`] is returned in
+/// that case, and [`aggregate`] can be used to find the misbehaving
+/// participant.
+pub fn aggregate_streaming<C, I>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: I,
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<Signature<C>, Error<C>>
+where
+    C: Ciphersuite,
+    I: IntoIterator<Item = (Identifier<C>, round2::SignatureShare<C>)>,
+{
+    let mut seen = BTreeSet::new();
+    let mut z = <<C::Group as Group>::Field>::zero();
+
+    for (identifier, signature_share) in signature_shares {
+        if !signing_package
+            .signing_commitments()
+            .contains_key(&identifier)
+            || !seen.insert(identifier)
+        {
+            return Err(Error::UnknownIdentifier { identifier });
+        }
+        z = z + signature_share.share;
+    }
+
+    if let Some(identifier) = signing_package
+        .signing_commitments()
+        .keys()
+        .find(|identifier| !seen.contains(identifier))
+    {
+        return Err(Error::UnknownIdentifier {
+            identifier: *identifier,
+        });
+    }
+
+    let (group_commitment, _binding_factor_list) =
+        compute_group_commitment_and_binding_factors(signing_package, &pubkeys.verifying_key, &[])?;
+
+    let signature = Signature::from_components(group_commitment, z);
+
+    signing_package.verify_signature(&pubkeys.verifying_key, &signature)?;
+
+    Ok(signature)
+}
+
+/// Verifies each signature share individually against the given
+/// `challenge`, returning an [`Error::InvalidSignatureShares`] naming every
+/// invalid one.
+pub(crate) fn verify_signature_shares<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    binding_factor_list: &BindingFactorList<C>,
+    challenge: &Challenge<C>,
+) -> Result<(), Error<C>> {
+    // Verify all the signature shares, collecting every invalid one so that
+    // all misbehaving participants can be excluded at once.
+    let mut culprits = BTreeSet::new();
+    for (signature_share_identifier, signature_share) in signature_shares {
+        // Look up the public key for this signer, where `signer_pubkey` = _G.ScalarBaseMult(s[i])_,
+        // and where s[i] is a secret share of the constant term of _f_, the secret polynomial.
+        let signer_pubkey = pubkeys
+            .verifying_shares
+            .get(signature_share_identifier)
+            .ok_or(Error::UnknownIdentifier {
+                identifier: *signature_share_identifier,
+            })?;
+
+        // Compute Lagrange coefficient.
+        let lambda_i = derive_interpolating_value(signature_share_identifier, signing_package)?;
+
+        let binding_factor = binding_factor_list.get(signature_share_identifier).ok_or(
+            Error::UnknownIdentifier {
+                identifier: *signature_share_identifier,
+            },
+        )?;
+
+        // Compute the commitment share.
+        let R_share = signing_package
+            .signing_commitment(signature_share_identifier)
+            .ok_or(Error::UnknownIdentifier {
+                identifier: *signature_share_identifier,
+            })?
+            .to_group_commitment_share(binding_factor);
+
+        // Compute relation values to verify this signature share.
+        if signature_share
+            .verify(
                 *signature_share_identifier,
                 &R_share,
                 signer_pubkey,
                 lambda_i,
-                &challenge,
-            )?;
+                challenge,
+            )
+            .is_err()
+        {
+            culprits.insert(*signature_share_identifier);
         }
+    }
 
-        // We should never reach here; but we return the verification error to be safe.
-        return Err(err);
+    if !culprits.is_empty() {
+        return Err(Error::InvalidSignatureShares { culprits });
     }
 
-    #[cfg(not(feature = "cheater-detection"))]
-    verification_result?;
+    Ok(())
+}
 
-    Ok(signature)
+/// Verifies all the signature shares of a signing operation at once.
+///
+/// Instead of verifying each share like [`aggregate`] does when the aggregate
+/// signature is invalid, this checks a random linear combination of the
+/// verification equations of all the shares with a single multiscalar
+/// multiplication. If that check fails, the shares are verified one by one
+/// and an [`Error::InvalidSignatureShares`] naming every invalid one is
+/// returned.
+///
+/// `signature_shares` must have a share for each participant in the
+/// `signing_package`, all of which must be in `pubkeys`; otherwise
+/// [`Error::UnknownIdentifier`] is returned.
+pub fn verify_signature_shares_batch<C: Ciphersuite, R: RngCore + CryptoRng>(
+    signing_package: &SigningPackage<C>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    mut rng: R,
+) -> Result<(), Error<C>> {
+    check_signature_shares_match(signing_package, signature_shares)?;
+    signing_package.check_against_public_package(pubkeys)?;
+
+    let (group_commitment, binding_factor_list) =
+        compute_group_commitment_and_binding_factors(signing_package, &pubkeys.verifying_key, &[])?;
+    let challenge = signing_package.challenge(&group_commitment, &pubkeys.verifying_key);
+
+    // Each share must satisfy z_i * G = D_i + rho_i * E_i + c * lambda_i * Y_i,
+    // so check that sum(r_i * z_i) * G equals the sum of the right-hand sides
+    // multiplied by random r_i.
+    let mut z_sum = <<C::Group as Group>::Field>::zero();
+    let mut scalars = Vec::with_capacity(3 * signature_shares.len());
+    let mut elements = Vec::with_capacity(3 * signature_shares.len());
+    for (identifier, signature_share) in signature_shares {
+        let commitment =
+            signing_package
+                .signing_commitment(identifier)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: *identifier,
+                })?;
+        let binding_factor =
+            binding_factor_list
+                .get(identifier)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: *identifier,
+                })?;
+        let verifying_share =
+            pubkeys
+                .verifying_shares
+                .get(identifier)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: *identifier,
+                })?;
+        let lambda_i = derive_interpolating_value(identifier, signing_package)?;
+
+        let r = <<C::Group as Group>::Field>::random(&mut rng);
+        z_sum = z_sum + r * signature_share.share;
+
+        scalars.push(r);
+        elements.push(commitment.hiding.0);
+        scalars.push(r * binding_factor.0);
+        elements.push(commitment.binding.0);
+        scalars.push(r * challenge.0 * lambda_i);
+        elements.push(verifying_share.0);
+    }
+
+    let result: Element<C> = VartimeMultiscalarMul::<C>::vartime_multiscalar_mul(scalars, elements);
+
+    if <C::Group>::generator() * z_sum != result {
+        verify_signature_shares(
+            signing_package,
+            pubkeys,
+            signature_shares,
+            &binding_factor_list,
+            &challenge,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// A participant's contribution to a signing operation, as reported by
+/// [`signing_report`].
+#[derive(Clone, Getters)]
+pub struct SignerReport<C: Ciphersuite> {
+    /// The Lagrange coefficient of the participant in the signing set, which
+    /// weights their share of the signing key.
+    lambda_i: Scalar<C>,
+    /// Whether the participant's signature share is valid.
+    verified: bool,
+    /// The participant's share of the group commitment.
+    group_commitment_share: round1::GroupCommitmentShare<C>,
+}
+
+/// A report of the contribution of each participant to a signing operation,
+/// returned by [`signing_report`].
+#[derive(Clone, Getters)]
+pub struct SigningReport<C: Ciphersuite> {
+    /// The report of each participant in the signing operation.
+    signers: BTreeMap<Identifier<C>, SignerReport<C>>,
+}
+
+impl<C> SigningReport<C>
+where
+    C: Ciphersuite,
+{
+    /// Returns the identifiers of all the participants whose signature share
+    /// is invalid, i.e. the culprits [`aggregate`] would report in an
+    /// [`Error::InvalidSignatureShares`].
+    pub fn culprits(&self) -> BTreeSet<Identifier<C>> {
+        self.signers
+            .iter()
+            .filter(|(_, signer)| !signer.verified)
+            .map(|(identifier, _)| *identifier)
+            .collect()
+    }
+}
+
+/// Reports the contribution of each participant to a signing operation: their
+/// Lagrange coefficient, whether their signature share is valid, and their
+/// share of the group commitment.
+///
+/// This only gathers values that are computed during aggregation and is
+/// intended for monitoring; it does not produce a signature. Invalid shares
+/// are reported with `verified` set to `false` instead of returning an error.
+///
+/// `signature_shares` must have a share for each participant in the
+/// `signing_package`, all of which must be in `pubkeys`; otherwise
+/// [`Error::UnknownIdentifier`] is returned.
+pub fn signing_report<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<SigningReport<C>, Error<C>> {
+    check_signature_shares_match(signing_package, signature_shares)?;
+
+    let (group_commitment, binding_factor_list) =
+        compute_group_commitment_and_binding_factors(signing_package, &pubkeys.verifying_key, &[])?;
+    let challenge = signing_package.challenge(&group_commitment, &pubkeys.verifying_key);
+
+    let mut signers = BTreeMap::new();
+    for (identifier, signing_commitments) in signing_package.signing_commitments() {
+        let signature_share = signature_shares
+            .get(identifier)
+            .ok_or(Error::UnknownIdentifier {
+                identifier: *identifier,
+            })?;
+        let verifying_share =
+            pubkeys
+                .verifying_shares
+                .get(identifier)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: *identifier,
+                })?;
+        let binding_factor =
+            binding_factor_list
+                .get(identifier)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: *identifier,
+                })?;
+
+        let lambda_i = derive_interpolating_value(identifier, signing_package)?;
+        let group_commitment_share = signing_commitments.to_group_commitment_share(binding_factor);
+        let verified = signature_share
+            .verify(
+                *identifier,
+                &group_commitment_share,
+                verifying_share,
+                lambda_i,
+                &challenge,
+            )
+            .is_ok();
+
+        signers.insert(
+            *identifier,
+            SignerReport {
+                lambda_i,
+                verified,
+                group_commitment_share,
+            },
+        );
+    }
+
+    Ok(SigningReport { signers })
+}
+
+/// Checks that the verifying shares of the participants in the signing set
+/// interpolate to the group verifying key, i.e. that they belong to the same
+/// group and that there are at least `min_signers` of them.
+fn check_verifying_shares_interpolate<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<(), Error<C>> {
+    let mut interpolated_verifying_key = <C::Group>::identity();
+    for identifier in signing_package.signing_commitments().keys() {
+        let verifying_share =
+            pubkeys
+                .verifying_shares
+                .get(identifier)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: *identifier,
+                })?;
+        let lambda_i = derive_interpolating_value(identifier, signing_package)?;
+        interpolated_verifying_key = interpolated_verifying_key + verifying_share.0 * lambda_i;
+    }
+    if interpolated_verifying_key != pubkeys.verifying_key.element {
+        return Err(Error::MismatchedGroupPublicKey);
+    }
+    Ok(())
+}
+
+/// Checks that all the verifying shares in the [`keys::PublicKeyPackage`]
+/// interpolate to the group verifying key, i.e. that they belong to the same
+/// group. Unlike [`check_verifying_shares_interpolate`], this doesn't depend on
+/// how many participants are signing.
+fn check_public_key_package_interpolates<C: Ciphersuite>(
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<(), Error<C>> {
+    let identifiers: BTreeSet<Identifier<C>> = pubkeys.verifying_shares.keys().cloned().collect();
+    let mut interpolated_verifying_key = <C::Group>::identity();
+    for (identifier, verifying_share) in &pubkeys.verifying_shares {
+        let lambda_i = compute_lagrange_coefficient(&identifiers, None, *identifier)?;
+        interpolated_verifying_key = interpolated_verifying_key + verifying_share.0 * lambda_i;
+    }
+    if interpolated_verifying_key != pubkeys.verifying_key.element {
+        return Err(Error::MismatchedGroupPublicKey);
+    }
+    Ok(())
+}
+
+/// The party that aggregates the signature shares into the final signature.
+///
+/// The combiner can be different from the coordinator that generates the
+/// [`SigningPackage`]: it only needs the [`SigningPackage`] and the
+/// [`keys::PublicKeyPackage`], and it does not take part in the signing
+/// rounds. Like the coordinator, it learns no secret information; it is
+/// trusted to publish the signature and to report misbehaving participants,
+/// so a malicious combiner can at worst cause a denial of service.
+///
+/// A [`Combiner`] is only created if the [`SigningPackage`] is consistent
+/// with the [`keys::PublicKeyPackage`], so that a signing package created for
+/// another group is rejected before any share is accepted.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+pub struct Combiner<C: Ciphersuite> {
+    /// The signing package of the signing operation.
+    signing_package: SigningPackage<C>,
+    /// The public key package of the group.
+    pubkeys: keys::PublicKeyPackage<C>,
+}
+
+impl<C> Combiner<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new [`Combiner`] for the given signing operation.
+    ///
+    /// Returns [`Error::UnknownIdentifier`] if a participant in the signing
+    /// package has no verifying share in `pubkeys`, and
+    /// [`Error::MismatchedGroupPublicKey`] if their verifying shares don't
+    /// interpolate to the group verifying key, e.g. if the signing package is
+    /// for another group or has fewer than `min_signers` participants.
+    pub fn new(
+        signing_package: SigningPackage<C>,
+        pubkeys: keys::PublicKeyPackage<C>,
+    ) -> Result<Self, Error<C>> {
+        check_verifying_shares_interpolate(&signing_package, &pubkeys)?;
+
+        Ok(Self {
+            signing_package,
+            pubkeys,
+        })
+    }
+
+    /// Aggregates the signature shares into the final signature. See
+    /// [`aggregate`].
+    pub fn aggregate(
+        &self,
+        signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    ) -> Result<Signature<C>, Error<C>> {
+        aggregate(&self.signing_package, signature_shares, &self.pubkeys)
+    }
 }
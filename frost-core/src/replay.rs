@@ -0,0 +1,116 @@
+//! Replay protection for verifiers that must accept at most one signature by
+//! a group on each message.
+
+use std::collections::BTreeSet;
+
+use crate::{keys::PublicKeyPackage, Ciphersuite, Error, Header, Signature};
+
+#[cfg(feature = "serialization")]
+use crate::serialization::{Deserialize, Serialize};
+
+/// The domain separator of the entries of a [`VerifierReplayCache`].
+const REPLAY_DOMAIN: &[u8] = b"replay";
+
+/// Records the (verifying key, message) pairs of the signatures accepted by
+/// [`VerifierReplayCache::verify_once`], so that a second signature on the
+/// same message by the same group is rejected.
+///
+/// Entries are keyed on the group's verifying key, which the signatures are
+/// verified against, so replays are also detected across
+/// [`PublicKeyPackage`]s of the same group with different verifying shares,
+/// e.g. after the shares were refreshed.
+///
+/// Only hashes of the pairs are stored. The cache can be serialized to be
+/// persisted between runs; it grows with each accepted signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct VerifierReplayCache<C: Ciphersuite> {
+    /// Serialization header
+    pub(crate) header: Header<C>,
+    /// The hashes of the accepted (verifying key, message) pairs.
+    pub(crate) seen: BTreeSet<Vec<u8>>,
+}
+
+impl<C> VerifierReplayCache<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new, empty [`VerifierReplayCache`].
+    pub fn new() -> Self {
+        Self {
+            header: Header::default(),
+            seen: BTreeSet::new(),
+        }
+    }
+
+    /// Verifies the `signature` on `message` by the group of `pubkeys` and
+    /// records the message as signed by that group.
+    ///
+    /// Returns [`Error::ReplayDetected`] if a signature on the same message
+    /// by the same group was already accepted, and the verification error if
+    /// the signature is invalid; in both cases nothing is recorded.
+    pub fn verify_once(
+        &mut self,
+        pubkeys: &PublicKeyPackage<C>,
+        message: &[u8],
+        signature: &Signature<C>,
+    ) -> Result<(), Error<C>> {
+        let entry = Self::entry(pubkeys, message);
+        if self.seen.contains(&entry) {
+            return Err(Error::ReplayDetected);
+        }
+
+        pubkeys.verifying_key().verify(message, signature)?;
+
+        self.seen.insert(entry);
+        Ok(())
+    }
+
+    /// Returns the number of recorded (verifying key, message) pairs.
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Returns true if no pair was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Hashes a (verifying key, message) pair. The serialized verifying key
+    /// has a fixed length, so the concatenation is unambiguous.
+    fn entry(pubkeys: &PublicKeyPackage<C>, message: &[u8]) -> Vec<u8> {
+        let mut preimage = vec![];
+        preimage.extend_from_slice(REPLAY_DOMAIN);
+        preimage.extend_from_slice(pubkeys.verifying_key().serialize().as_ref());
+        preimage.extend_from_slice(message);
+
+        C::H4(&preimage).as_ref().to_vec()
+    }
+}
+
+impl<C> Default for VerifierReplayCache<C>
+where
+    C: Ciphersuite,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> VerifierReplayCache<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+}
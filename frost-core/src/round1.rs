@@ -10,7 +10,7 @@ use derive_getters::Getters;
 use hex::FromHex;
 
 use rand_core::{CryptoRng, RngCore};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use crate as frost;
 use crate::{
@@ -19,12 +19,16 @@ use crate::{
 };
 
 #[cfg(feature = "serde")]
-use crate::serialization::ElementSerialization;
+use crate::serialization::{ElementSerialization, ScalarSerialization};
 
 use super::{keys::SigningShare, Identifier};
 
 /// A scalar that is a signing nonce.
 #[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(try_from = "ScalarSerialization<C>"))]
+#[cfg_attr(feature = "serde", serde(into = "ScalarSerialization<C>"))]
 pub struct Nonce<C: Ciphersuite>(pub(super) Scalar<C>);
 
 impl<C> Nonce<C>
@@ -50,17 +54,43 @@ where
         Self::nonce_generate_from_random_bytes(secret, random_bytes)
     }
 
+    /// Generates a new uniformly random signing nonce like [`Nonce::new`], but
+    /// also hashes the given auxiliary randomness into it, to further hedge
+    /// against a biased RNG.
+    ///
+    /// This is not part of the spec.
+    pub fn new_with_aux_rand<R>(secret: &SigningShare<C>, aux_rand: &[u8; 32], rng: &mut R) -> Self
+    where
+        R: CryptoRng + RngCore,
+    {
+        let mut random_bytes = [0; 32];
+        rng.fill_bytes(&mut random_bytes[..]);
+
+        Self::nonce_generate_from_random_bytes_and_aux_rand(secret, random_bytes, aux_rand)
+    }
+
     /// Generates a nonce from the given random bytes.
     /// This function allows testing and MUST NOT be made public.
     pub(crate) fn nonce_generate_from_random_bytes(
         secret: &SigningShare<C>,
         random_bytes: [u8; 32],
+    ) -> Self {
+        Self::nonce_generate_from_random_bytes_and_aux_rand(secret, random_bytes, &[])
+    }
+
+    /// Generates a nonce from the given random bytes and auxiliary randomness.
+    /// This function allows testing and MUST NOT be made public.
+    pub(crate) fn nonce_generate_from_random_bytes_and_aux_rand(
+        secret: &SigningShare<C>,
+        random_bytes: [u8; 32],
+        aux_rand: &[u8],
     ) -> Self {
         let secret_enc = <<C::Group as Group>::Field>::serialize(&secret.0);
 
         let input: Vec<u8> = random_bytes
             .iter()
             .chain(secret_enc.as_ref().iter())
+            .chain(aux_rand.iter())
             .cloned()
             .collect();
 
@@ -82,6 +112,28 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<C> TryFrom<ScalarSerialization<C>> for Nonce<C>
+where
+    C: Ciphersuite,
+{
+    type Error = Error<C>;
+
+    fn try_from(value: ScalarSerialization<C>) -> Result<Self, Self::Error> {
+        Self::deserialize(value.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> From<Nonce<C>> for ScalarSerialization<C>
+where
+    C: Ciphersuite,
+{
+    fn from(value: Nonce<C>) -> Self {
+        Self(value.serialize())
+    }
+}
+
 impl<C> Zeroize for Nonce<C>
 where
     C: Ciphersuite,
@@ -207,6 +259,10 @@ where
 /// operation; re-using nonces will result in leakage of a signer's long-lived
 /// signing key.
 #[derive(Clone, Zeroize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(from = "SigningNoncesSerialization<C>"))]
+#[cfg_attr(feature = "serde", serde(into = "SigningNoncesSerialization<C>"))]
 pub struct SigningNonces<C: Ciphersuite> {
     /// The hiding [`Nonce`].
     pub(crate) hiding: Nonce<C>,
@@ -238,6 +294,18 @@ where
         Self::from_nonces(hiding, binding)
     }
 
+    /// Generates a new signing nonce, hashing the given auxiliary randomness
+    /// into both nonces. See [`Nonce::new_with_aux_rand`].
+    pub fn new_with_aux_rand<R>(secret: &SigningShare<C>, aux_rand: &[u8; 32], rng: &mut R) -> Self
+    where
+        R: CryptoRng + RngCore,
+    {
+        let hiding = Nonce::<C>::new_with_aux_rand(secret, aux_rand, rng);
+        let binding = Nonce::<C>::new_with_aux_rand(secret, aux_rand, rng);
+
+        Self::from_nonces(hiding, binding)
+    }
+
     /// Generates a new [`SigningNonces`] from a pair of [`Nonce`]. This is
     /// useful internally since [`SigningNonces`] precompute the respective
     /// commitments.
@@ -265,6 +333,173 @@ where
     }
 }
 
+#[cfg(feature = "serialization")]
+impl<C> SigningNonces<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    ///
+    /// # Security
+    ///
+    /// The serialized nonces are as sensitive as the signing share: anyone
+    /// who learns them together with the resulting signature share can
+    /// recover the signing share. Only serialize nonces to move them into
+    /// secret storage, and never use the same serialized nonces for more
+    /// than one signing operation; restoring a copy that was already used
+    /// will leak the signing key.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    ///
+    /// The commitments are recomputed from the nonces. See
+    /// [`SigningNonces::serialize`] for the security considerations.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+}
+
+// Used to encode a SigningNonces. The commitments are not encoded since they
+// can be derived from the nonces, which keeps the encoding canonical.
+#[cfg(feature = "serde")]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct SigningNoncesSerialization<C: Ciphersuite> {
+    /// Serialization header
+    header: Header<C>,
+    /// The hiding [`Nonce`].
+    hiding: Nonce<C>,
+    /// The binding [`Nonce`].
+    binding: Nonce<C>,
+}
+
+#[cfg(feature = "serde")]
+impl<C> From<SigningNoncesSerialization<C>> for SigningNonces<C>
+where
+    C: Ciphersuite,
+{
+    fn from(value: SigningNoncesSerialization<C>) -> Self {
+        Self::from_nonces(value.hiding, value.binding)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> From<SigningNonces<C>> for SigningNoncesSerialization<C>
+where
+    C: Ciphersuite,
+{
+    fn from(value: SigningNonces<C>) -> Self {
+        Self {
+            header: Header::default(),
+            hiding: value.hiding,
+            binding: value.binding,
+        }
+    }
+}
+
+/// The hiding and binding [`Nonce`]s of a [`SigningNonces`], split so that
+/// they can be stored in separate locations (e.g. two HSMs) and only combined
+/// transiently when signing with [`round2::sign_split`].
+///
+/// Each half is (de)serialized independently, with [`Nonce::serialize`] and
+/// [`Nonce::deserialize`].
+///
+/// [`round2::sign_split`]: crate::round2::sign_split
+#[derive(Clone, Zeroize)]
+pub struct SplitNonces<C: Ciphersuite> {
+    /// The hiding [`Nonce`].
+    pub(crate) hiding: Nonce<C>,
+    /// The binding [`Nonce`].
+    pub(crate) binding: Nonce<C>,
+    /// The commitments to the nonces.
+    #[zeroize(skip)]
+    pub(crate) commitments: SigningCommitments<C>,
+}
+
+impl<C> SplitNonces<C>
+where
+    C: Ciphersuite,
+{
+    /// Creates a [`SplitNonces`] from the two halves and the commitments
+    /// published in round 1, checking that each half matches its commitment.
+    pub fn new(
+        hiding: Nonce<C>,
+        binding: Nonce<C>,
+        commitments: SigningCommitments<C>,
+    ) -> Result<Self, Error<C>> {
+        Self::check(&hiding, &binding, &commitments)?;
+
+        Ok(Self {
+            hiding,
+            binding,
+            commitments,
+        })
+    }
+
+    /// Checks that the `hiding` and `binding` nonces match the given
+    /// `commitments`, returning [`Error::IncorrectCommitment`] otherwise.
+    pub(crate) fn check(
+        hiding: &Nonce<C>,
+        binding: &Nonce<C>,
+        commitments: &SigningCommitments<C>,
+    ) -> Result<(), Error<C>> {
+        if NonceCommitment::from(hiding) != commitments.hiding
+            || NonceCommitment::from(binding) != commitments.binding
+        {
+            return Err(Error::IncorrectCommitment);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the hiding [`Nonce`]
+    pub fn hiding(&self) -> &Nonce<C> {
+        &self.hiding
+    }
+
+    /// Gets the binding [`Nonce`]
+    pub fn binding(&self) -> &Nonce<C> {
+        &self.binding
+    }
+
+    /// Gets the commitments to the nonces.
+    pub fn commitments(&self) -> &SigningCommitments<C> {
+        &self.commitments
+    }
+}
+
+impl<C> Drop for SplitNonces<C>
+where
+    C: Ciphersuite,
+{
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl<C> ZeroizeOnDrop for SplitNonces<C> where C: Ciphersuite {}
+
+impl<C> From<SigningNonces<C>> for SplitNonces<C>
+where
+    C: Ciphersuite,
+{
+    fn from(mut nonces: SigningNonces<C>) -> Self {
+        let split_nonces = Self {
+            hiding: nonces.hiding.clone(),
+            binding: nonces.binding.clone(),
+            commitments: nonces.commitments,
+        };
+        // The nonces now live in `split_nonces`; wipe the consumed copy.
+        nonces.zeroize();
+
+        split_nonces
+    }
+}
+
 /// Published by each participant in the first round of the signing protocol.
 ///
 /// This step can be batched if desired by the implementation. Each
@@ -296,12 +531,24 @@ where
         }
     }
 
+    /// Returns the length in bytes of the encoding of [`SigningCommitments`]
+    /// used in the group commitment list, i.e. the encoded hiding commitment
+    /// followed by the encoded binding commitment.
+    ///
+    /// This doesn't include the serialization header added by
+    /// [`SigningCommitments::serialize`].
+    pub fn encoded_len() -> usize {
+        2 * <C::Group>::serialize(&<C::Group>::generator())
+            .as_ref()
+            .len()
+    }
+
     /// Computes the [signature commitment share] from these round one signing commitments.
     ///
     /// [signature commitment share]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-signature-share-verificatio
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
-    pub(super) fn to_group_commitment_share(
+    pub(crate) fn to_group_commitment_share(
         self,
         binding_factor: &frost::BindingFactor<C>,
     ) -> GroupCommitmentShare<C> {
@@ -336,9 +583,37 @@ where
 
 /// One signer's share of the group commitment, derived from their individual signing commitments
 /// and the binding factor _rho_.
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct GroupCommitmentShare<C: Ciphersuite>(pub(super) Element<C>);
 
+impl<C> GroupCommitmentShare<C>
+where
+    C: Ciphersuite,
+{
+    /// Deserialize [`GroupCommitmentShare`] from bytes
+    pub fn deserialize(bytes: <C::Group as Group>::Serialization) -> Result<Self, Error<C>> {
+        <C::Group>::deserialize(&bytes)
+            .map(|element| Self(element))
+            .map_err(|e| e.into())
+    }
+
+    /// Serialize [`GroupCommitmentShare`] to bytes
+    pub fn serialize(&self) -> <C::Group as Group>::Serialization {
+        <C::Group>::serialize(&self.0)
+    }
+}
+
+impl<C> Debug for GroupCommitmentShare<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("GroupCommitmentShare")
+            .field(&hex::encode(self.serialize()))
+            .finish()
+    }
+}
+
 /// Encode the list of group signing commitments.
 ///
 /// Implements [`encode_group_commitment_list()`] from the spec.
@@ -418,3 +693,106 @@ where
         vec_signing_commitments.pop().expect("must have 1 element"),
     )
 }
+
+/// Restarts round 1 for a participant, e.g. when the coordinator restarts a
+/// signing operation that stalled.
+///
+/// The old nonces are consumed and zeroized, so that they can't be used to
+/// sign with the old commitments anymore, and fresh nonces and commitments
+/// are generated like [`commit`] does. The coordinator should record the old
+/// commitments in its [`StaleCommitments`].
+pub fn recommit<C, R>(
+    mut old_nonces: SigningNonces<C>,
+    secret: &SigningShare<C>,
+    rng: &mut R,
+) -> (SigningNonces<C>, SigningCommitments<C>)
+where
+    C: Ciphersuite,
+    R: CryptoRng + RngCore,
+{
+    old_nonces.zeroize();
+    commit(secret, rng)
+}
+
+/// The signing commitments invalidated by restarting round 1, kept by the
+/// coordinator.
+///
+/// When a signing operation is restarted, the coordinator should
+/// [`invalidate`](StaleCommitments::invalidate) the commitments of the old
+/// [`SigningPackage`](crate::SigningPackage), and
+/// [`check`](StaleCommitments::check) every signing package before sending
+/// it to the participants or aggregating its signature shares, so that a
+/// commitment or share from the old signing operation arriving late is not
+/// used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaleCommitments<C: Ciphersuite> {
+    commitments: BTreeMap<Identifier<C>, Vec<SigningCommitments<C>>>,
+}
+
+impl<C> StaleCommitments<C>
+where
+    C: Ciphersuite,
+{
+    /// Create an empty set of stale commitments.
+    pub fn new() -> Self {
+        Self {
+            commitments: BTreeMap::new(),
+        }
+    }
+
+    /// Mark all the commitments of the given signing package as stale.
+    pub fn invalidate(&mut self, signing_package: &frost::SigningPackage<C>) {
+        for (identifier, commitments) in signing_package.signing_commitments() {
+            self.commitments
+                .entry(*identifier)
+                .or_default()
+                .push(*commitments);
+        }
+    }
+
+    /// Check that none of the commitments of the given signing package is
+    /// stale, returning [`Error::StaleCommitment`] otherwise.
+    pub fn check(&self, signing_package: &frost::SigningPackage<C>) -> Result<(), Error<C>> {
+        for (identifier, commitments) in signing_package.signing_commitments() {
+            if self
+                .commitments
+                .get(identifier)
+                .is_some_and(|stale| stale.contains(commitments))
+            {
+                return Err(Error::StaleCommitment {
+                    culprit: *identifier,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C> Default for StaleCommitments<C>
+where
+    C: Ciphersuite,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Like [`commit`], but also hashes the given auxiliary randomness into the
+/// signing nonces, to further hedge against a biased RNG. This does not change
+/// how signatures are verified.
+///
+/// This is not part of the spec.
+pub fn commit_with_aux_rand<C, R>(
+    secret: &SigningShare<C>,
+    aux_rand: &[u8; 32],
+    rng: &mut R,
+) -> (SigningNonces<C>, SigningCommitments<C>)
+where
+    C: Ciphersuite,
+    R: CryptoRng + RngCore,
+{
+    let signing_nonces = SigningNonces::new_with_aux_rand(secret, aux_rand, rng);
+    let signing_commitments = SigningCommitments::from(&signing_nonces);
+
+    (signing_nonces, signing_commitments)
+}
@@ -1,10 +1,15 @@
 //! FROST Round 2 functionality and types, for signature share generation
 
-use std::fmt::{self, Debug};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Debug},
+};
+
+use zeroize::Zeroize;
 
 use crate as frost;
 use crate::{
-    challenge, Challenge, Ciphersuite, Error, Field, Group, {round1, *},
+    Challenge, Ciphersuite, Error, Field, Group, {round1, *},
 };
 
 #[cfg(feature = "serde")]
@@ -61,6 +66,14 @@ impl<C> SignatureShare<C>
 where
     C: Ciphersuite,
 {
+    /// Returns the length in bytes of a serialized [`SignatureShare`], i.e. the
+    /// length of an encoded scalar.
+    pub fn encoded_len() -> usize {
+        <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero())
+            .as_ref()
+            .len()
+    }
+
     /// Deserialize [`SignatureShare`] from bytes
     pub fn deserialize(
         bytes: <<C::Group as Group>::Field as Field>::Serialization,
@@ -101,6 +114,46 @@ where
 
         Ok(())
     }
+
+    /// Tests if a signature share issued by a participant is valid, using the
+    /// given Lagrange coefficient `lambda_i` instead of the one derived from
+    /// the signing set in the `signing_package`.
+    ///
+    /// This is useful to check what happens with custom or unusual signing
+    /// sets; in regular signing use [`crate::aggregate`], which verifies the
+    /// shares with the correct coefficients if the aggregate signature is
+    /// invalid.
+    pub fn verify_with_lambda(
+        &self,
+        identifier: Identifier<C>,
+        signing_package: &SigningPackage<C>,
+        verifying_share: &frost::keys::VerifyingShare<C>,
+        verifying_key: &VerifyingKey<C>,
+        lambda_i: Scalar<C>,
+    ) -> Result<(), Error<C>> {
+        let (group_commitment, binding_factor_list) =
+            compute_group_commitment_and_binding_factors(signing_package, verifying_key, &[])?;
+        let binding_factor = binding_factor_list
+            .get(&identifier)
+            .ok_or(Error::UnknownIdentifier { identifier })?;
+
+        // Compute the commitment share.
+        let group_commitment_share = signing_package
+            .signing_commitment(&identifier)
+            .ok_or(Error::UnknownIdentifier { identifier })?
+            .to_group_commitment_share(binding_factor);
+
+        // Compute the per-message challenge.
+        let challenge = signing_package.challenge(&group_commitment, verifying_key);
+
+        self.verify(
+            identifier,
+            &group_commitment_share,
+            verifying_share,
+            lambda_i,
+            &challenge,
+        )
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -149,19 +202,121 @@ where
     }
 }
 
+/// A cache of Lagrange coefficients for a fixed set of signers.
+///
+/// The coefficients only depend on the set of signers, so when signing
+/// repeatedly with the same set they can be computed once and reused with
+/// [`sign_cached`]. The cache is cleared whenever it is used with a
+/// [`SigningPackage`] with a different set of signers.
+#[derive(Clone)]
+pub struct LambdaCache<C: Ciphersuite> {
+    /// The set of signers the cached coefficients were computed for.
+    signers: BTreeSet<Identifier<C>>,
+    /// The cached coefficients, keyed by the identifier of the signer.
+    lambdas: BTreeMap<Identifier<C>, Scalar<C>>,
+}
+
+impl<C> LambdaCache<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new, empty cache.
+    pub fn new() -> Self {
+        Self {
+            signers: BTreeSet::new(),
+            lambdas: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the Lagrange coefficient of `identifier` for the set of
+    /// signers in `signing_package`, computing it if it's not cached.
+    ///
+    /// If the set of signers differs from the one of the cached coefficients,
+    /// the cache is cleared first.
+    pub fn lambda(
+        &mut self,
+        identifier: &Identifier<C>,
+        signing_package: &SigningPackage<C>,
+    ) -> Result<Scalar<C>, Error<C>> {
+        if !self
+            .signers
+            .iter()
+            .eq(signing_package.signing_commitments().keys())
+        {
+            self.signers = signing_package
+                .signing_commitments()
+                .keys()
+                .cloned()
+                .collect();
+            self.lambdas.clear();
+        }
+
+        if let Some(lambda_i) = self.lambdas.get(identifier) {
+            return Ok(*lambda_i);
+        }
+
+        let lambda_i = frost::derive_interpolating_value(identifier, signing_package)?;
+        self.lambdas.insert(*identifier, lambda_i);
+
+        Ok(lambda_i)
+    }
+}
+
+impl<C> Default for LambdaCache<C>
+where
+    C: Ciphersuite,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Multiplies scalars by a participant's signing share.
+///
+/// The signing share is only used by [`sign_with_provider`] through this
+/// trait, which allows storing it outside of the signer's process, e.g. in a
+/// hardware security module, so that it is not exposed by a leak of the
+/// signer's memory (crash dumps, swap, etc.). [`keys::SigningShare`] implements
+/// it in software.
+///
+/// This does not protect the share from whoever can call the provider:
+/// [`ScalarMulProvider::mul_share`] returns `s·x` for a public `x`, from which
+/// the signing share `s` is trivially recovered (e.g. with `x = 1`). Access to
+/// the provider must therefore be restricted to the trusted signer.
+pub trait ScalarMulProvider<C: Ciphersuite> {
+    /// Returns the generator multiplied by the signing share and `scalar`.
+    fn mul_base(&self, scalar: &Scalar<C>) -> Element<C>;
+
+    /// Returns the signing share multiplied by `scalar`.
+    fn mul_share(&self, scalar: &Scalar<C>) -> Scalar<C>;
+}
+
+impl<C> ScalarMulProvider<C> for keys::SigningShare<C>
+where
+    C: Ciphersuite,
+{
+    fn mul_base(&self, scalar: &Scalar<C>) -> Element<C> {
+        <C::Group>::generator() * (self.0 * *scalar)
+    }
+
+    fn mul_share(&self, scalar: &Scalar<C>) -> Scalar<C> {
+        self.0 * *scalar
+    }
+}
+
 /// Compute the signature share for a signing operation.
 #[cfg_attr(feature = "internals", visibility::make(pub))]
 #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
-fn compute_signature_share<C: Ciphersuite>(
+pub(crate) fn compute_signature_share<C: Ciphersuite, P: ScalarMulProvider<C>>(
     signer_nonces: &round1::SigningNonces<C>,
     binding_factor: BindingFactor<C>,
     lambda_i: <<<C as Ciphersuite>::Group as Group>::Field as Field>::Scalar,
-    key_package: &keys::KeyPackage<C>,
+    signing_share: &P,
     challenge: Challenge<C>,
 ) -> SignatureShare<C> {
     let z_share: <<C::Group as Group>::Field as Field>::Scalar = signer_nonces.hiding.0
         + (signer_nonces.binding.0 * binding_factor.0)
-        + (lambda_i * key_package.signing_share.0 * challenge.0);
+        + signing_share.mul_share(&(lambda_i * challenge.0));
 
     SignatureShare::<C> { share: z_share }
 }
@@ -177,55 +332,233 @@ fn compute_signature_share<C: Ciphersuite>(
 /// Assumes the participant has already determined which nonce corresponds with
 /// the commitment that was assigned by the coordinator in the SigningPackage.
 ///
+/// Returns [`Error::SuspiciousCommitments`] if the same commitment appears for
+/// two participants in the SigningPackage, since that means they share nonces.
+///
 /// [`sign`]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#name-round-two-signature-share-g
 pub fn sign<C: Ciphersuite>(
     signing_package: &SigningPackage<C>,
     signer_nonces: &round1::SigningNonces<C>,
     key_package: &frost::keys::KeyPackage<C>,
 ) -> Result<SignatureShare<C>, Error<C>> {
-    if signing_package.signing_commitments().len() < key_package.min_signers as usize {
-        return Err(Error::IncorrectNumberOfCommitments);
+    sign_with_lambda(
+        signing_package,
+        signer_nonces,
+        key_package.identifier,
+        &key_package.verifying_key,
+        key_package.min_signers,
+        &key_package.signing_share,
+        || frost::derive_interpolating_value(key_package.identifier(), signing_package),
+        &[],
+        |group_commitment| signing_package.challenge(group_commitment, &key_package.verifying_key),
+    )
+}
+
+/// Like [`sign`], but with the hiding and binding nonces supplied separately,
+/// e.g. from the two halves of a [`round1::SplitNonces`] stored in separate
+/// locations.
+///
+/// Returns [`Error::IncorrectCommitment`] if either nonce doesn't match its
+/// commitment in `commitments`. The nonces are only combined for the duration
+/// of this call, and the combined copy is zeroized before returning.
+pub fn sign_split<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    hiding_nonce: &round1::Nonce<C>,
+    binding_nonce: &round1::Nonce<C>,
+    commitments: &round1::SigningCommitments<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<SignatureShare<C>, Error<C>> {
+    round1::SplitNonces::check(hiding_nonce, binding_nonce, commitments)?;
+
+    let mut signer_nonces = round1::SigningNonces {
+        hiding: hiding_nonce.clone(),
+        binding: binding_nonce.clone(),
+        commitments: *commitments,
+    };
+    let signature_share = sign(signing_package, &signer_nonces, key_package);
+    signer_nonces.zeroize();
+
+    signature_share
+}
+
+/// Runs every validation and computation of [`sign`] but discards the
+/// resulting [`SignatureShare`], returning only whether signing would succeed.
+///
+/// This allows checking that a [`SigningPackage`] is ready to be signed, e.g.
+/// to show it in a UI, without releasing a share.
+pub fn sign_dry_run<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<(), Error<C>> {
+    sign(signing_package, signer_nonces, key_package).map(|_| ())
+}
+
+/// Like [`sign`], but takes only the public parts of the participant's
+/// [`KeyPackage`](frost::keys::KeyPackage) and multiplies by the signing share
+/// using the given [`ScalarMulProvider`], so that the signing share doesn't
+/// need to be stored by the signer. The signer can still recover it through
+/// the provider; see [`ScalarMulProvider`] for the threat model.
+///
+/// Returns [`Error::InvalidSecretShare`] if the signing share of the provider
+/// doesn't match the `verifying_share`.
+pub fn sign_with_provider<C: Ciphersuite, P: ScalarMulProvider<C>>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    identifier: Identifier<C>,
+    verifying_share: &frost::keys::VerifyingShare<C>,
+    verifying_key: &VerifyingKey<C>,
+    min_signers: u16,
+    signing_share: &P,
+) -> Result<SignatureShare<C>, Error<C>> {
+    if signing_share.mul_base(&<<C::Group as Group>::Field>::one()) != verifying_share.to_element()
+    {
+        return Err(Error::InvalidSecretShare {
+            culprit: identifier,
+        });
+    }
+
+    sign_with_lambda(
+        signing_package,
+        signer_nonces,
+        identifier,
+        verifying_key,
+        min_signers,
+        signing_share,
+        || frost::derive_interpolating_value(&identifier, signing_package),
+        &[],
+        |group_commitment| signing_package.challenge(group_commitment, verifying_key),
+    )
+}
+
+/// Like [`sign`], but looks up the Lagrange coefficient of the signer in the
+/// given [`LambdaCache`], computing and storing it if missing.
+///
+/// This avoids recomputing the coefficient in each signing round when
+/// signing repeatedly with the same set of participants.
+pub fn sign_cached<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    cache: &mut LambdaCache<C>,
+) -> Result<SignatureShare<C>, Error<C>> {
+    sign_with_lambda(
+        signing_package,
+        signer_nonces,
+        key_package.identifier,
+        &key_package.verifying_key,
+        key_package.min_signers,
+        &key_package.signing_share,
+        || cache.lambda(key_package.identifier(), signing_package),
+        &[],
+        |group_commitment| signing_package.challenge(group_commitment, &key_package.verifying_key),
+    )
+}
+
+/// Computes the participant's share of the group commitment from the nonces
+/// they sign with, i.e. `hiding + binding * rho`, to be sent to a combiner
+/// using [`frost::aggregate_with_commitment_shares`] along with the signature
+/// share.
+///
+/// Returns [`Error::NotInSigningSet`] if the participant has no commitment in
+/// the signing package.
+pub fn group_commitment_share<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<round1::GroupCommitmentShare<C>, Error<C>> {
+    if !signing_package
+        .signing_commitments
+        .contains_key(&key_package.identifier)
+    {
+        return Err(Error::NotInSigningSet);
     }
 
-    // Validate the signer's commitment is present in the signing package
+    let (_, binding_factor_list) = compute_group_commitment_and_binding_factors(
+        signing_package,
+        &key_package.verifying_key,
+        &[],
+    )?;
+    let binding_factor =
+        binding_factor_list
+            .get(&key_package.identifier)
+            .ok_or(Error::UnknownIdentifier {
+                identifier: key_package.identifier,
+            })?;
+
+    Ok(signer_nonces
+        .commitments
+        .to_group_commitment_share(binding_factor))
+}
+
+/// Shared implementation of [`sign`], [`sign_with_provider`],
+/// [`sign_cached`] and [`designated::sign`](crate::designated::sign), which
+/// only differ in how the signing share is held, how the Lagrange coefficient
+/// of the signer is obtained, and how the binding factors and the challenge
+/// are computed.
+///
+/// `additional_prefix` is passed to
+/// [`compute_group_commitment_and_binding_factors`], and `challenge` computes
+/// the challenge from the resulting group commitment.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn sign_with_lambda<C, P, F, G>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    identifier: Identifier<C>,
+    verifying_key: &VerifyingKey<C>,
+    min_signers: u16,
+    signing_share: &P,
+    lambda_i: F,
+    additional_prefix: &[u8],
+    challenge: G,
+) -> Result<SignatureShare<C>, Error<C>>
+where
+    C: Ciphersuite,
+    P: ScalarMulProvider<C>,
+    F: FnOnce() -> Result<Scalar<C>, Error<C>>,
+    G: FnOnce(&GroupCommitment<C>) -> Challenge<C>,
+{
+    // Validate the signer was selected for this signing round, i.e. their
+    // commitment is present in the signing package
     let commitment = signing_package
         .signing_commitments
-        .get(&key_package.identifier)
-        .ok_or(Error::MissingCommitment)?;
+        .get(&identifier)
+        .ok_or(Error::NotInSigningSet)?;
+
+    signing_package.check_min_commitments(min_signers)?;
 
     // Validate if the signer's commitment exists
     if &signer_nonces.commitments != commitment {
         return Err(Error::IncorrectCommitment);
     }
 
+    // Refuse to sign if nonces are shared between signers.
+    signing_package.check_distinct_commitments()?;
+
     // Encodes the signing commitment list produced in round one as part of generating [`BindingFactor`], the
-    // binding factor.
-    let binding_factor_list: BindingFactorList<C> =
-        compute_binding_factor_list(signing_package, &key_package.verifying_key, &[]);
+    // binding factor, and computes the group commitment from the same commitments.
+    let (group_commitment, binding_factor_list) = compute_group_commitment_and_binding_factors(
+        signing_package,
+        verifying_key,
+        additional_prefix,
+    )?;
     let binding_factor: frost::BindingFactor<C> = binding_factor_list
-        .get(&key_package.identifier)
-        .ok_or(Error::UnknownIdentifier)?
+        .get(&identifier)
+        .ok_or(Error::UnknownIdentifier { identifier })?
         .clone();
 
-    // Compute the group commitment from signing commitments produced in round one.
-    let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
-
     // Compute Lagrange coefficient.
-    let lambda_i = frost::derive_interpolating_value(key_package.identifier(), signing_package)?;
+    let lambda_i = lambda_i()?;
 
     // Compute the per-message challenge.
-    let challenge = challenge::<C>(
-        &group_commitment.0,
-        &key_package.verifying_key,
-        signing_package.message.as_slice(),
-    );
+    let challenge = challenge(&group_commitment);
 
     // Compute the Schnorr signature share.
     let signature_share = compute_signature_share(
         signer_nonces,
         binding_factor,
         lambda_i,
-        key_package,
+        signing_share,
         challenge,
     );
 
@@ -2,7 +2,7 @@
 
 use debugless_unwrap::DebuglessUnwrap;
 
-use crate::{Ciphersuite, Element, Error, Field, Group, Scalar};
+use crate::{Ciphersuite, Element, Error, Field, Group, GroupCommitment, Scalar};
 
 /// A Schnorr signature over some prime order group (or subgroup).
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -29,6 +29,26 @@ where
         Self { R, z }
     }
 
+    /// Create a Signature from the group commitment `R` of a signing
+    /// operation and the sum `z` of the signature shares.
+    ///
+    /// This is what [`crate::aggregate`] returns, and allows checking its
+    /// output against shares summed manually.
+    pub fn from_components(R: GroupCommitment<C>, z: Scalar<C>) -> Self {
+        Self { R: R.0, z }
+    }
+
+    /// Returns the length in bytes of a serialized [`Signature`], i.e. the
+    /// length of an encoded group element followed by an encoded scalar.
+    pub fn encoded_len() -> usize {
+        <C::Group>::serialize(&<C::Group>::generator())
+            .as_ref()
+            .len()
+            + <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero())
+                .as_ref()
+                .len()
+    }
+
     /// Converts bytes as [`Ciphersuite::SignatureSerialization`] into a `Signature<C>`.
     pub fn deserialize(bytes: C::SignatureSerialization) -> Result<Self, Error<C>> {
         // To compute the expected length of the encoded point, encode the generator
@@ -8,6 +8,10 @@ pub mod coefficient_commitment;
 pub mod helpers;
 pub mod proptests;
 pub mod repairable;
+pub mod timing;
+pub mod toy;
+#[cfg(feature = "serialization")]
+pub mod transcript;
 pub mod vectors;
 pub mod vectors_dkg;
 pub mod vss_commitment;
@@ -1,13 +1,18 @@
 //! Ciphersuite-generic test functions.
 #![allow(clippy::type_complexity)]
 
-use std::{collections::BTreeMap, convert::TryFrom};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+};
 
 use crate as frost;
 use crate::{
-    keys::PublicKeyPackage, Error, Field, Group, Identifier, Signature, SigningKey, VerifyingKey,
+    keys::PublicKeyPackage, Error, Field, FieldError, Group, Identifier, Signature, SigningKey,
+    VerifyingKey,
 };
 use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroize;
 
 use crate::Ciphersuite;
 
@@ -19,6 +24,17 @@ pub fn check_zero_key_fails<C: Ciphersuite>() {
     assert_eq!(r, Err(Error::MalformedSigningKey));
 }
 
+/// Test if creating a zero Identifier fails, since f(0) is the shared secret
+pub fn check_zero_identifier_fails<C: Ciphersuite>() {
+    let r = Identifier::<C>::try_from(0u16);
+    assert_eq!(r, Err(FieldError::InvalidZeroScalar.into()));
+
+    let zero = <<<C as Ciphersuite>::Group as Group>::Field>::zero();
+    let encoded_zero = <<<C as Ciphersuite>::Group as Group>::Field>::serialize(&zero);
+    let r = Identifier::<C>::deserialize(&encoded_zero);
+    assert_eq!(r, Err(FieldError::InvalidZeroScalar.into()));
+}
+
 /// Test share generation with a Ciphersuite
 pub fn check_share_generation<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
     let secret = crate::SigningKey::<C>::new(&mut rng);
@@ -69,7 +85,9 @@ pub fn check_share_generation<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R
 
     assert_eq!(
         frost::keys::reconstruct::<C>(&key_packages).unwrap_err(),
-        Error::DuplicatedIdentifier
+        Error::DuplicatedIdentifier {
+            identifier: *key_packages[1].identifier()
+        }
     );
 }
 
@@ -304,7 +322,13 @@ fn check_sign_errors<C: Ciphersuite + PartialEq>(
     let signing_package = frost::SigningPackage::new(commitments, signing_package.message());
 
     let r = frost::round2::sign(&signing_package, &signing_nonces, &key_package);
-    assert_eq!(r, Err(Error::IncorrectNumberOfCommitments));
+    assert_eq!(
+        r,
+        Err(Error::TooFewCommitments {
+            have: signing_package.signing_commitments().len(),
+            need: *key_package.min_signers() as usize,
+        })
+    );
 }
 
 fn check_aggregate_errors<C: Ciphersuite + PartialEq>(
@@ -337,7 +361,26 @@ fn check_aggregate_corrupted_share<C: Ciphersuite + PartialEq>(
     signature_shares.get_mut(&id).unwrap().share = signature_shares[&id].share + one;
     let e = frost::aggregate(&signing_package, &signature_shares, &pubkey_package).unwrap_err();
     assert_eq!(e.culprit(), Some(id));
-    assert_eq!(e, Error::InvalidSignatureShare { culprit: id });
+    assert_eq!(
+        e,
+        Error::InvalidSignatureShares {
+            culprits: BTreeSet::from([id])
+        }
+    );
+
+    // Corrupt another share; both culprits are reported at once
+    let other_id = *signature_shares.keys().last().unwrap();
+    assert_ne!(id, other_id);
+    signature_shares.get_mut(&other_id).unwrap().share = signature_shares[&other_id].share + one;
+    let e = frost::aggregate(&signing_package, &signature_shares, &pubkey_package).unwrap_err();
+    assert_eq!(e.culprit(), Some(id));
+    assert_eq!(e.culprits(), BTreeSet::from([id, other_id]));
+    assert_eq!(
+        e,
+        Error::InvalidSignatureShares {
+            culprits: BTreeSet::from([id, other_id])
+        }
+    );
 }
 
 /// Test NCC-E008263-4VP audit finding (PublicKeyPackage).
@@ -523,6 +566,75 @@ where
     check_sign(min_signers, key_packages, rng, pubkeys).unwrap()
 }
 
+/// Test that the DKG secret packages wipe their secrets when zeroized.
+pub fn check_dkg_secret_packages_zeroize<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let zero = <<C::Group as Group>::Field>::zero();
+
+    let mut round1_secret_packages = BTreeMap::new();
+    let mut round1_packages = BTreeMap::new();
+    for participant_index in 1..=max_signers {
+        let participant_identifier: frost::Identifier<C> =
+            participant_index.try_into().expect("should be nonzero");
+        let (round1_secret_package, round1_package) =
+            frost::keys::dkg::part1(participant_identifier, max_signers, min_signers, &mut rng)
+                .unwrap();
+        round1_secret_packages.insert(participant_identifier, round1_secret_package);
+        round1_packages.insert(participant_identifier, round1_package);
+    }
+
+    let (identifier, round1_secret_package) = round1_secret_packages.pop_first().unwrap();
+    assert!(round1_secret_package
+        .coefficients
+        .iter()
+        .all(|c| *c != zero));
+    let mut zeroized = round1_secret_package.clone();
+    zeroized.zeroize();
+    assert_eq!(zeroized.coefficients.len(), min_signers as usize);
+    assert!(zeroized.coefficients.iter().all(|c| *c == zero));
+
+    round1_packages.remove(&identifier);
+    let (round2_secret_package, _) =
+        frost::keys::dkg::part2(round1_secret_package, &round1_packages).unwrap();
+    assert!(round2_secret_package.secret_share != zero);
+    let mut zeroized = round2_secret_package.clone();
+    zeroized.zeroize();
+    assert!(zeroized.secret_share == zero);
+}
+
+/// Test that secret shares and key packages wipe their signing shares when
+/// zeroized, and don't print them when formatted with `Debug`.
+pub fn check_key_packages_zeroize<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let zero = <<C::Group as Group>::Field>::zero();
+
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let secret_share = shares.into_values().next().unwrap();
+    let key_package = frost::keys::KeyPackage::try_from(secret_share.clone()).unwrap();
+    let secret = hex::encode(key_package.signing_share().serialize());
+
+    assert!(!format!("{:?}", secret_share).contains(&secret));
+    assert!(!format!("{:?}", key_package).contains(&secret));
+
+    let mut zeroized = secret_share.clone();
+    zeroized.zeroize();
+    assert!(zeroized.signing_share.0 == zero);
+    assert_eq!(zeroized.commitment, secret_share.commitment);
+
+    let mut zeroized = key_package.clone();
+    zeroized.zeroize();
+    assert!(zeroized.signing_share.0 == zero);
+    assert_eq!(zeroized.verifying_share, key_package.verifying_share);
+}
+
 /// Check that calling dkg::part3() with distinct sets of participants fail.
 fn check_part3_different_participants<C: Ciphersuite>(
     max_signers: u16,
@@ -581,7 +693,12 @@ pub fn check_sign_with_dealer_and_identifiers<C: Ciphersuite, R: RngCore + Crypt
         &mut rng,
     )
     .unwrap_err();
-    assert_eq!(err, Error::DuplicatedIdentifier);
+    assert_eq!(
+        err,
+        Error::DuplicatedIdentifier {
+            identifier: identifiers[1]
+        }
+    );
 
     // Check incorrect number of identifiers
 
@@ -638,8 +755,41 @@ fn check_part2_error<C: Ciphersuite>(
     mut round1_packages: BTreeMap<frost::Identifier<C>, frost::keys::dkg::round1::Package<C>>,
 ) {
     let one = <<C as Ciphersuite>::Group as Group>::Field::one();
-    // Corrupt a PoK
     let id = *round1_packages.keys().next().unwrap();
+    let min_signers = round1_secret_package.min_signers as usize;
+
+    // Truncate a commitment
+    let mut truncated_packages = round1_packages.clone();
+    truncated_packages.get_mut(&id).unwrap().commitment.0.pop();
+    let e =
+        frost::keys::dkg::part2(round1_secret_package.clone(), &truncated_packages).unwrap_err();
+    assert_eq!(
+        e,
+        Error::InvalidCoefficientCount {
+            expected: min_signers,
+            got: min_signers - 1
+        }
+    );
+
+    // Pad a commitment
+    let mut padded_packages = round1_packages.clone();
+    let extra = padded_packages[&id].commitment.0[0];
+    padded_packages
+        .get_mut(&id)
+        .unwrap()
+        .commitment
+        .0
+        .push(extra);
+    let e = frost::keys::dkg::part2(round1_secret_package.clone(), &padded_packages).unwrap_err();
+    assert_eq!(
+        e,
+        Error::InvalidCoefficientCount {
+            expected: min_signers,
+            got: min_signers + 1
+        }
+    );
+
+    // Corrupt a PoK
     round1_packages.get_mut(&id).unwrap().proof_of_knowledge.z =
         round1_packages[&id].proof_of_knowledge.z + one;
     let e = frost::keys::dkg::part2(round1_secret_package, &round1_packages).unwrap_err();
@@ -647,6 +797,49 @@ fn check_part2_error<C: Ciphersuite>(
     assert_eq!(e, Error::InvalidProofOfKnowledge { culprit: id });
 }
 
+/// Test that verifying a [`SecretShare`](frost::keys::SecretShare) against the
+/// threshold rejects commitments with too few or too many coefficients.
+pub fn check_secret_share_coefficient_count<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let share = shares.into_values().next().unwrap();
+
+    assert_eq!(
+        share.verify_with_min_signers(min_signers).unwrap(),
+        share.verify().unwrap()
+    );
+
+    // Truncate the commitment
+    let mut truncated = share.clone();
+    truncated.commitment.0.pop();
+    assert_eq!(
+        truncated.verify_with_min_signers(min_signers),
+        Err(Error::InvalidCoefficientCount {
+            expected: min_signers as usize,
+            got: min_signers as usize - 1
+        })
+    );
+
+    // Pad the commitment
+    let mut padded = share.clone();
+    let extra = padded.commitment.0[0];
+    padded.commitment.0.push(extra);
+    assert_eq!(
+        padded.verify_with_min_signers(min_signers),
+        Err(Error::InvalidCoefficientCount {
+            expected: min_signers as usize,
+            got: min_signers as usize + 1
+        })
+    );
+}
+
 /// Test Error culprit method.
 pub fn check_error_culprit<C: Ciphersuite>() {
     let identifier: frost::Identifier<C> = 42u16.try_into().unwrap();
@@ -655,14 +848,23 @@ pub fn check_error_culprit<C: Ciphersuite>() {
         culprit: identifier,
     };
     assert_eq!(e.culprit(), Some(identifier));
+    assert_eq!(e.culprits(), BTreeSet::from([identifier]));
 
     let e = Error::InvalidProofOfKnowledge {
         culprit: identifier,
     };
     assert_eq!(e.culprit(), Some(identifier));
 
+    let other_identifier: frost::Identifier<C> = 7u16.try_into().unwrap();
+    let e = Error::InvalidSignatureShares {
+        culprits: BTreeSet::from([identifier, other_identifier]),
+    };
+    assert_eq!(e.culprit(), Some(other_identifier));
+    assert_eq!(e.culprits(), BTreeSet::from([identifier, other_identifier]));
+
     let e: Error<C> = Error::InvalidSignature;
     assert_eq!(e.culprit(), None);
+    assert!(e.culprits().is_empty());
 }
 
 /// Test identifier derivation with a Ciphersuite
@@ -675,6 +877,56 @@ pub fn check_identifier_derivation<C: Ciphersuite>() {
     assert!(id1a != id2);
 }
 
+/// Test identifier derivation from verifying shares with a Ciphersuite
+pub fn check_identifier_derivation_from_verifying_share<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let (_, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let identifiers: Vec<_> = pubkeys
+        .verifying_shares()
+        .values()
+        .map(|verifying_share| {
+            let id = Identifier::derive_from_verifying_share(verifying_share).unwrap();
+            // Derivation is deterministic
+            assert!(id == Identifier::derive_from_verifying_share(verifying_share).unwrap());
+            assert!(id == Identifier::derive(verifying_share.serialize().as_ref()).unwrap());
+            id
+        })
+        .collect();
+
+    // Distinct verifying shares map to distinct identifiers
+    let unique: std::collections::BTreeSet<_> = identifiers.iter().collect();
+    assert_eq!(unique.len(), identifiers.len());
+}
+
+/// Test the short fingerprint shown by the `Display` impl of `Identifier`
+pub fn check_identifier_display<C: Ciphersuite>() {
+    let id = Identifier::<C>::try_from(1).unwrap();
+    let serialized = id.serialize();
+    let bytes = serialized.as_ref();
+    let expected = format!(
+        "id:0x{}…{}",
+        hex::encode(&bytes[..4]),
+        hex::encode(&bytes[bytes.len() - 4..])
+    );
+    assert_eq!(id.to_string(), expected);
+    assert_ne!(
+        id.to_string(),
+        Identifier::<C>::try_from(2).unwrap().to_string()
+    );
+
+    // Errors with a culprit show its fingerprint
+    let e: Error<C> = Error::InvalidSignatureShare { culprit: id };
+    assert!(e.to_string().contains(&expected));
+}
+
 /// Checks the signer's identifier is included in the package
 pub fn check_sign_with_missing_identifier<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
     ////////////////////////////////////////////////////////////////////////////
@@ -753,7 +1005,17 @@ pub fn check_sign_with_missing_identifier<C: Ciphersuite, R: RngCore + CryptoRng
     // Each participant generates their signature share.
     let signature_share = frost::round2::sign(&signing_package, nonces_to_use, key_package_1);
 
-    assert_eq!(signature_share, Err(Error::MissingCommitment))
+    assert_eq!(signature_share, Err(Error::NotInSigningSet));
+
+    // A participant not in the signing set is told so before the number of
+    // commitments is checked.
+    let mut commitments = signing_package.signing_commitments().clone();
+    commitments.remove(&id_4);
+    let signing_package = frost::SigningPackage::new(commitments, message);
+
+    let signature_share = frost::round2::sign(&signing_package, nonces_to_use, key_package_1);
+
+    assert_eq!(signature_share, Err(Error::NotInSigningSet));
 }
 
 /// Checks the signer's commitment is valid
@@ -825,3 +1087,2951 @@ pub fn check_sign_with_incorrect_commitments<C: Ciphersuite, R: RngCore + Crypto
     assert!(signature_share.is_err());
     assert!(signature_share == Err(Error::IncorrectCommitment))
 }
+
+/// Checks that the group commitment and binding factors match their
+/// definitions in the spec.
+pub fn check_group_commitment_and_binding_factors<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut commitments_map: BTreeMap<frost::Identifier<C>, frost::round1::SigningCommitments<C>> =
+        BTreeMap::new();
+    for (identifier, share) in shares {
+        let (_nonces, commitments) = frost::round1::commit(share.signing_share(), &mut rng);
+        commitments_map.insert(identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let (group_commitment, binding_factor_list) =
+        frost::compute_group_commitment_and_binding_factors(
+            &signing_package,
+            pubkeys.verifying_key(),
+            &[],
+        )
+        .unwrap();
+
+    // Recompute both from their definitions in the spec.
+    let preimages = signing_package.binding_factor_preimages(pubkeys.verifying_key(), &[]);
+    assert_eq!(binding_factor_list.0.len(), preimages.len());
+    let mut expected_group_commitment = <C::Group>::identity();
+    for (identifier, preimage) in &preimages {
+        let binding_factor = C::H1(preimage);
+        assert_eq!(
+            binding_factor_list.get(identifier),
+            Some(&frost::BindingFactor(binding_factor))
+        );
+        let commitment = signing_package.signing_commitment(identifier).unwrap();
+        expected_group_commitment = expected_group_commitment
+            + commitment.hiding().0
+            + commitment.binding().0 * binding_factor;
+    }
+    assert!(group_commitment.0 == expected_group_commitment);
+}
+
+/// Test aggregating signature shares received as serialized bytes.
+pub fn check_aggregate_from_bytes<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares: BTreeMap<frost::Identifier<C>, Vec<u8>> = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share.serialize().as_ref().to_vec());
+    }
+
+    // Valid shares aggregate into a valid signature
+    let signature =
+        frost::aggregate_from_bytes(&signing_package, &signature_shares, &pubkeys).unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+
+    let culprit = *signature_shares.keys().next().unwrap();
+
+    // A share with the wrong length is reported as malformed
+    let mut truncated_shares = signature_shares.clone();
+    truncated_shares.get_mut(&culprit).unwrap().pop();
+    let r = frost::aggregate_from_bytes(&signing_package, &truncated_shares, &pubkeys);
+    assert_eq!(r, Err(Error::MalformedSignatureShare { culprit }));
+    assert_eq!(r.unwrap_err().culprit(), Some(culprit));
+
+    // A share that is not a valid scalar is reported as malformed
+    let mut invalid_shares = signature_shares.clone();
+    invalid_shares
+        .get_mut(&culprit)
+        .unwrap()
+        .iter_mut()
+        .for_each(|b| *b = 0xff);
+    let r = frost::aggregate_from_bytes(&signing_package, &invalid_shares, &pubkeys);
+    assert_eq!(r, Err(Error::MalformedSignatureShare { culprit }));
+}
+
+/// Test that a signature can be linked back to the signing package it was
+/// produced from through its group commitment.
+pub fn check_group_commitment_matches_signature<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let message = "message to sign".as_bytes();
+    let mut signing_packages = Vec::new();
+    let mut signatures = Vec::new();
+    for _ in 0..2 {
+        let mut nonces_map = BTreeMap::new();
+        let mut commitments_map = BTreeMap::new();
+        for (identifier, key_package) in &key_packages {
+            let (nonces, commitments) =
+                frost::round1::commit(key_package.signing_share(), &mut rng);
+            nonces_map.insert(*identifier, nonces);
+            commitments_map.insert(*identifier, commitments);
+        }
+        let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+        let mut signature_shares = BTreeMap::new();
+        for (identifier, key_package) in &key_packages {
+            let nonces = nonces_map.get(identifier).unwrap();
+            let signature_share =
+                frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+            signature_shares.insert(*identifier, signature_share);
+        }
+        let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+        signing_packages.push(signing_package);
+        signatures.push(signature);
+    }
+
+    let group_commitment = signing_packages[0]
+        .group_commitment(pubkeys.verifying_key())
+        .unwrap();
+
+    assert!(group_commitment.matches_signature(&signatures[0]));
+    assert!(!group_commitment.matches_signature(&signatures[1]));
+}
+
+/// Test that a signature built from the group commitment and the shares
+/// summed by hand is the one returned by `aggregate`.
+pub fn check_signature_from_components<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    let group_commitment = signing_package
+        .group_commitment(pubkeys.verifying_key())
+        .unwrap();
+    let z = signature_shares
+        .values()
+        .fold(<<C::Group as Group>::Field>::zero(), |z, share| {
+            z + share.share
+        });
+
+    assert!(Signature::from_components(group_commitment, z) == signature);
+}
+
+/// Test aggregating with a [`Combiner`](frost::Combiner) that is separate
+/// from the coordinator.
+pub fn check_combiner<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    // Coordinator: collects commitments and creates the signing package
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map.clone(), message);
+
+    // Combiner: only receives the signing package and the public key package
+    let combiner = frost::Combiner::new(signing_package.clone(), pubkeys.clone()).unwrap();
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let signature = combiner.aggregate(&signature_shares).unwrap();
+    assert!(pubkeys.verifying_key().verify(message, &signature).is_ok());
+    assert!(signature == frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap());
+
+    // A signing package with fewer than min_signers participants is rejected
+    let mut commitments_below_threshold = commitments_map.clone();
+    commitments_below_threshold.pop_first();
+    let signing_package_below_threshold =
+        frost::SigningPackage::new(commitments_below_threshold, message);
+    assert_eq!(
+        frost::Combiner::new(signing_package_below_threshold, pubkeys.clone()).err(),
+        Some(Error::MismatchedGroupPublicKey)
+    );
+
+    // A public key package of another group with the same identifiers is
+    // rejected
+    let (_, other_pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        frost::Combiner::new(signing_package.clone(), other_pubkeys).err(),
+        Some(Error::MismatchedGroupPublicKey)
+    );
+
+    // A signer unknown to the public key package is rejected
+    let mut commitments_with_unknown = commitments_map;
+    let unknown_commitments = *commitments_with_unknown.values().next().unwrap();
+    let unknown = Identifier::try_from(max_signers + 1).unwrap();
+    commitments_with_unknown.insert(unknown, unknown_commitments);
+    let signing_package_with_unknown =
+        frost::SigningPackage::new(commitments_with_unknown, message);
+    assert_eq!(
+        frost::Combiner::new(signing_package_with_unknown, pubkeys).err(),
+        Some(Error::UnknownIdentifier {
+            identifier: unknown
+        })
+    );
+}
+
+/// Test verifying the signature shares of a signing operation in a batch.
+pub fn check_verify_signature_shares_batch<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    frost::verify_signature_shares_batch(&signing_package, &pubkeys, &signature_shares, &mut rng)
+        .unwrap();
+
+    // A single invalid share fails the batch and is identified
+    let culprit = *signature_shares.keys().nth(1).unwrap();
+    let mut invalid_signature_shares = signature_shares.clone();
+    let invalid_share = invalid_signature_shares.get_mut(&culprit).unwrap();
+    invalid_share.share = invalid_share.share + <<C::Group as Group>::Field>::one();
+    assert_eq!(
+        frost::verify_signature_shares_batch(
+            &signing_package,
+            &pubkeys,
+            &invalid_signature_shares,
+            &mut rng
+        ),
+        Err(Error::InvalidSignatureShares {
+            culprits: BTreeSet::from([culprit])
+        })
+    );
+
+    // A missing share is rejected
+    let mut missing_signature_shares = signature_shares;
+    missing_signature_shares.remove(&culprit);
+    assert_eq!(
+        frost::verify_signature_shares_batch(
+            &signing_package,
+            &pubkeys,
+            &missing_signature_shares,
+            &mut rng
+        ),
+        Err(Error::UnknownIdentifier {
+            identifier: culprit
+        })
+    );
+}
+
+/// Test the report of the participants' contributions to a signing operation.
+pub fn check_signing_report<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let report = frost::signing_report(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert_eq!(
+        report.signers().keys().collect::<Vec<_>>(),
+        key_packages.keys().collect::<Vec<_>>()
+    );
+
+    // All shares are valid, the weighted verifying shares interpolate to the
+    // group verifying key, and the group commitment shares add up to the
+    // group commitment.
+    let (group_commitment, _) = frost::compute_group_commitment_and_binding_factors(
+        &signing_package,
+        pubkeys.verifying_key(),
+        &[],
+    )
+    .unwrap();
+    let mut verifying_key = <C::Group>::identity();
+    let mut commitment = <C::Group>::identity();
+    for (identifier, signer) in report.signers() {
+        assert!(*signer.verified());
+        assert!(
+            *signer.lambda_i()
+                == frost::derive_interpolating_value(identifier, &signing_package).unwrap()
+        );
+        verifying_key =
+            verifying_key + pubkeys.verifying_shares()[identifier].0 * *signer.lambda_i();
+        commitment = commitment + signer.group_commitment_share().0;
+    }
+    assert!(verifying_key == pubkeys.verifying_key().element);
+    assert!(commitment == group_commitment.0);
+
+    // An invalid share is reported as such
+    let culprit = *signature_shares.keys().next().unwrap();
+    let invalid_share = signature_shares.get_mut(&culprit).unwrap();
+    invalid_share.share = invalid_share.share + <<C::Group as Group>::Field>::one();
+    let report = frost::signing_report(&signing_package, &signature_shares, &pubkeys).unwrap();
+    for (identifier, signer) in report.signers() {
+        assert_eq!(*signer.verified(), *identifier != culprit);
+    }
+    assert_eq!(report.culprits(), BTreeSet::from([culprit]));
+
+    // With several invalid shares, the report lists the same culprits as
+    // the aggregation error.
+    let other_culprit = *signature_shares.keys().nth(1).unwrap();
+    let invalid_share = signature_shares.get_mut(&other_culprit).unwrap();
+    invalid_share.share = invalid_share.share + <<C::Group as Group>::Field>::one();
+    assert_eq!(
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys),
+        Err(Error::InvalidSignatureShares {
+            culprits: BTreeSet::from([culprit, other_culprit])
+        })
+    );
+    let report = frost::signing_report(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert_eq!(report.culprits(), BTreeSet::from([culprit, other_culprit]));
+}
+
+/// Test that aggregating with key material mixed from two groups that use
+/// the same identifiers is reported as a group verifying key mismatch.
+pub fn check_aggregate_mismatched_group_public_key<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares_a, pubkeys_a) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let (shares_b, _pubkeys_b) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares_a
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    // Replace the key material of the first participant with the one from
+    // the other group, keeping the verifying key of the first group.
+    let mixed_id = *key_packages.keys().next().unwrap();
+    let key_package_b = frost::keys::KeyPackage::try_from(shares_b[&mixed_id].clone()).unwrap();
+    let mixed_key_package = frost::keys::KeyPackage::new(
+        mixed_id,
+        *key_package_b.signing_share(),
+        *key_package_b.verifying_share(),
+        *key_packages[&mixed_id].verifying_key(),
+        min_signers,
+    );
+    key_packages.insert(mixed_id, mixed_key_package);
+
+    let mut verifying_shares = pubkeys_a.verifying_shares().clone();
+    verifying_shares.insert(mixed_id, *key_package_b.verifying_share());
+    let pubkeys = frost::keys::PublicKeyPackage::new(verifying_shares, *pubkeys_a.verifying_key());
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let r = frost::aggregate(&signing_package, &signature_shares, &pubkeys);
+    #[cfg(feature = "cheater-detection")]
+    assert_eq!(r, Err(Error::MismatchedGroupPublicKey));
+    #[cfg(not(feature = "cheater-detection"))]
+    assert_eq!(r, Err(Error::InvalidSignature));
+}
+
+/// Test verifying a signature share with a given Lagrange coefficient.
+pub fn check_verify_signature_share_with_lambda<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let (identifier, key_package) = key_packages.iter().next().unwrap();
+    let nonces = nonces_map.get(identifier).unwrap();
+    let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+
+    let lambda_i = frost::derive_interpolating_value(identifier, &signing_package).unwrap();
+
+    // The correct Lagrange coefficient verifies
+    signature_share
+        .verify_with_lambda(
+            *identifier,
+            &signing_package,
+            key_package.verifying_share(),
+            pubkeys.verifying_key(),
+            lambda_i,
+        )
+        .unwrap();
+
+    // A perturbed Lagrange coefficient does not
+    let one = <<C::Group as Group>::Field>::one();
+    let r = signature_share.verify_with_lambda(
+        *identifier,
+        &signing_package,
+        key_package.verifying_share(),
+        pubkeys.verifying_key(),
+        lambda_i + one,
+    );
+    assert_eq!(
+        r,
+        Err(Error::InvalidSignatureShare {
+            culprit: *identifier
+        })
+    );
+}
+
+/// A [`frost::round2::ScalarMulProvider`] that counts how many times the
+/// signing share is used.
+struct CountingProvider<C: Ciphersuite> {
+    signing_share: frost::keys::SigningShare<C>,
+    base_count: std::cell::Cell<usize>,
+    share_count: std::cell::Cell<usize>,
+}
+
+impl<C: Ciphersuite> frost::round2::ScalarMulProvider<C> for CountingProvider<C> {
+    fn mul_base(&self, scalar: &crate::Scalar<C>) -> crate::Element<C> {
+        self.base_count.set(self.base_count.get() + 1);
+        frost::round2::ScalarMulProvider::mul_base(&self.signing_share, scalar)
+    }
+
+    fn mul_share(&self, scalar: &crate::Scalar<C>) -> crate::Scalar<C> {
+        self.share_count.set(self.share_count.get() + 1);
+        frost::round2::ScalarMulProvider::mul_share(&self.signing_share, scalar)
+    }
+}
+
+/// Test that signing with a [`frost::round2::ScalarMulProvider`] routes the
+/// multiplications by the signing share through it.
+pub fn check_sign_with_provider<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let provider = CountingProvider {
+            signing_share: *key_package.signing_share(),
+            base_count: std::cell::Cell::new(0),
+            share_count: std::cell::Cell::new(0),
+        };
+        let signature_share = frost::round2::sign_with_provider(
+            &signing_package,
+            nonces,
+            *identifier,
+            key_package.verifying_share(),
+            key_package.verifying_key(),
+            *key_package.min_signers(),
+            &provider,
+        )
+        .unwrap();
+
+        assert_eq!(provider.base_count.get(), 1);
+        assert_eq!(provider.share_count.get(), 1);
+        assert_eq!(
+            signature_share,
+            frost::round2::sign(&signing_package, nonces, key_package).unwrap()
+        );
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    // A provider holding another participant's share is rejected.
+    let mut key_packages = key_packages.values();
+    let key_package = key_packages.next().unwrap();
+    let other_key_package = key_packages.next().unwrap();
+    let provider = CountingProvider {
+        signing_share: *other_key_package.signing_share(),
+        base_count: std::cell::Cell::new(0),
+        share_count: std::cell::Cell::new(0),
+    };
+    let r = frost::round2::sign_with_provider(
+        &signing_package,
+        nonces_map.get(key_package.identifier()).unwrap(),
+        *key_package.identifier(),
+        key_package.verifying_share(),
+        key_package.verifying_key(),
+        *key_package.min_signers(),
+        &provider,
+    );
+    assert_eq!(
+        r,
+        Err(Error::InvalidSecretShare {
+            culprit: *key_package.identifier()
+        })
+    );
+    assert_eq!(provider.share_count.get(), 0);
+}
+
+/// Test signing with nonces generated with auxiliary randomness.
+pub fn check_sign_with_aux_rand<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    // Different auxiliary randomness yields different commitments for the same
+    // signing share and random bytes.
+    let signing_share = key_packages.values().next().unwrap().signing_share();
+    let mut random_bytes = [0u8; 32];
+    rng.fill_bytes(&mut random_bytes);
+    let nonces_with_aux_rand = |aux_rand: &[u8]| {
+        frost::round1::SigningNonces::from_nonces(
+            frost::round1::Nonce::nonce_generate_from_random_bytes_and_aux_rand(
+                signing_share,
+                random_bytes,
+                aux_rand,
+            ),
+            frost::round1::Nonce::nonce_generate_from_random_bytes_and_aux_rand(
+                signing_share,
+                random_bytes,
+                aux_rand,
+            ),
+        )
+    };
+    assert!(
+        nonces_with_aux_rand(&[1u8; 32]).commitments
+            != nonces_with_aux_rand(&[2u8; 32]).commitments
+    );
+    // Empty auxiliary randomness matches the spec nonce generation
+    assert!(
+        frost::round1::Nonce::nonce_generate_from_random_bytes(signing_share, random_bytes)
+            == frost::round1::Nonce::nonce_generate_from_random_bytes_and_aux_rand(
+                signing_share,
+                random_bytes,
+                &[]
+            )
+    );
+
+    // Signing still works
+    let aux_rand = [42u8; 32];
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) =
+            frost::round1::commit_with_aux_rand(key_package.signing_share(), &aux_rand, &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
+
+/// Test restarting round 1 with fresh nonces, invalidating the old
+/// commitments.
+pub fn check_sign_after_recommit<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let message = "message to sign".as_bytes();
+    let mut old_nonces_map = BTreeMap::new();
+    let mut old_commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        old_nonces_map.insert(*identifier, nonces);
+        old_commitments_map.insert(*identifier, commitments);
+    }
+    let old_signing_package = frost::SigningPackage::new(old_commitments_map.clone(), message);
+
+    // The signing operation stalls and the coordinator restarts it
+    let mut stale_commitments = frost::round1::StaleCommitments::new();
+    stale_commitments.invalidate(&old_signing_package);
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let old_nonces = old_nonces_map.remove(identifier).unwrap();
+        let (nonces, commitments) =
+            frost::round1::recommit(old_nonces, key_package.signing_share(), &mut rng);
+        assert!(commitments != old_commitments_map[identifier]);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    // Participants can't sign against their old commitments anymore
+    for (identifier, key_package) in &key_packages {
+        assert_eq!(
+            frost::round2::sign(&old_signing_package, &nonces_map[identifier], key_package)
+                .unwrap_err(),
+            Error::IncorrectCommitment
+        );
+    }
+
+    // The coordinator rejects the old signing package, and a new one using a
+    // late old commitment
+    let culprit = *key_packages.keys().next().unwrap();
+    assert_eq!(
+        stale_commitments.check(&old_signing_package),
+        Err(Error::StaleCommitment { culprit })
+    );
+    let mut late_commitments_map = commitments_map.clone();
+    late_commitments_map.insert(culprit, old_commitments_map[&culprit]);
+    let late_signing_package = frost::SigningPackage::new(late_commitments_map, message);
+    assert_eq!(
+        stale_commitments.check(&late_signing_package),
+        Err(Error::StaleCommitment { culprit })
+    );
+    assert_eq!(
+        Error::<C>::StaleCommitment { culprit }.culprit(),
+        Some(culprit)
+    );
+
+    // Signing with the fresh commitments works
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    stale_commitments.check(&signing_package).unwrap();
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let signature_share =
+            frost::round2::sign(&signing_package, &nonces_map[identifier], key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert!(pubkeys.verifying_key().verify(message, &signature).is_ok());
+}
+
+/// Test that serialized [`SigningNonces`](frost::round1::SigningNonces) can be
+/// restored and produce the same signature share.
+#[cfg(feature = "serialization")]
+pub fn check_sign_with_serialized_nonces<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, nonces) in &nonces_map {
+        let bytes = nonces.serialize().unwrap();
+        let restored = frost::round1::SigningNonces::<C>::deserialize(&bytes).unwrap();
+
+        // The encoding is canonical and the commitments are recomputed
+        assert_eq!(restored.serialize().unwrap(), bytes);
+        assert_eq!(restored.commitments, nonces.commitments);
+        assert!(restored.hiding() == nonces.hiding());
+        assert!(restored.binding() == nonces.binding());
+
+        // Truncated encodings are rejected
+        assert_eq!(
+            frost::round1::SigningNonces::<C>::deserialize(&bytes[..bytes.len() - 1]).err(),
+            Some(Error::DeserializationError)
+        );
+
+        let key_package = &key_packages[identifier];
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        let restored_signature_share =
+            frost::round2::sign(&signing_package, &restored, key_package).unwrap();
+        assert_eq!(signature_share, restored_signature_share);
+        signature_shares.insert(*identifier, restored_signature_share);
+    }
+
+    let group_signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert!(pubkeys
+        .verifying_key()
+        .verify(message, &group_signature)
+        .is_ok());
+}
+
+/// Test selecting a random signing committee from a group.
+pub fn check_select_committee<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 10;
+    let min_signers = 4;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    for size in min_signers..=max_signers {
+        for _ in 0..10 {
+            let committee = frost::select_committee(&pubkeys, min_signers, size, &mut rng).unwrap();
+            assert_eq!(committee.len(), size as usize);
+            assert!(committee.len() >= min_signers as usize);
+            // Drawn from the group, without duplicates
+            assert!(committee.iter().all(|id| shares.contains_key(id)));
+            assert!(committee.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+
+    // Committees can't be below the threshold or larger than the group
+    assert_eq!(
+        frost::select_committee(&pubkeys, min_signers, min_signers - 1, &mut rng),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+    assert_eq!(
+        frost::select_committee(&pubkeys, min_signers, max_signers + 1, &mut rng),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+}
+
+/// Test that the advertised encoded lengths match the serialized values.
+pub fn check_encoded_lengths<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    for commitments in commitments_map.values() {
+        assert_eq!(
+            commitments.hiding().serialize().as_ref().len()
+                + commitments.binding().serialize().as_ref().len(),
+            frost::round1::SigningCommitments::<C>::encoded_len()
+        );
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.get(identifier).unwrap();
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        assert_eq!(
+            signature_share.serialize().as_ref().len(),
+            frost::round2::SignatureShare::<C>::encoded_len()
+        );
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert_eq!(
+        signature.serialize().as_ref().len(),
+        frost::Signature::<C>::encoded_len()
+    );
+}
+
+/// Test that `sign_dry_run` succeeds exactly when `sign` does.
+pub fn check_sign_dry_run<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in key_packages.iter().take(min_signers as usize) {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map.clone(), message);
+
+    let id_1 = Identifier::<C>::try_from(1).unwrap();
+    let id_2 = Identifier::<C>::try_from(2).unwrap();
+    let id_5 = Identifier::<C>::try_from(5).unwrap();
+
+    let check = |signing_package: &frost::SigningPackage<C>,
+                 nonces: &frost::round1::SigningNonces<C>,
+                 key_package: &frost::keys::KeyPackage<C>| {
+        let result = frost::round2::sign(signing_package, nonces, key_package).map(|_| ());
+        let dry_run = frost::round2::sign_dry_run(signing_package, nonces, key_package);
+        assert_eq!(result, dry_run);
+        dry_run
+    };
+
+    // Valid signing package
+    for (identifier, nonces) in &nonces_map {
+        assert!(check(&signing_package, nonces, &key_packages[identifier]).is_ok());
+    }
+
+    // Signer not in the signing set
+    let (nonces_5, _) = frost::round1::commit(key_packages[&id_5].signing_share(), &mut rng);
+    assert_eq!(
+        check(&signing_package, &nonces_5, &key_packages[&id_5]),
+        Err(Error::NotInSigningSet)
+    );
+
+    // Nonces not matching the commitment
+    assert_eq!(
+        check(&signing_package, &nonces_map[&id_2], &key_packages[&id_1]),
+        Err(Error::IncorrectCommitment)
+    );
+
+    // Not enough commitments
+    let mut small_commitments_map = commitments_map;
+    small_commitments_map.remove(&id_2);
+    let small_signing_package = frost::SigningPackage::new(small_commitments_map, message);
+    assert_eq!(
+        check(
+            &small_signing_package,
+            &nonces_map[&id_1],
+            &key_packages[&id_1]
+        ),
+        Err(Error::TooFewCommitments {
+            have: min_signers as usize - 1,
+            need: min_signers as usize,
+        })
+    );
+}
+
+/// Test that signing with a `LambdaCache` produces the same shares as regular
+/// signing, including after the set of signers changes.
+pub fn check_sign_cached<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut caches: BTreeMap<frost::Identifier<C>, frost::round2::LambdaCache<C>> = key_packages
+        .keys()
+        .map(|identifier| (*identifier, frost::round2::LambdaCache::new()))
+        .collect();
+
+    // Sign a few rounds with the same set, then with a different one.
+    let signer_sets = [[1u16, 2, 3], [1, 2, 3], [1, 2, 3], [2, 4, 5], [2, 4, 5]];
+
+    for signer_set in signer_sets {
+        let mut nonces_map = BTreeMap::new();
+        let mut commitments_map = BTreeMap::new();
+        for i in signer_set {
+            let identifier = Identifier::<C>::try_from(i).unwrap();
+            let (nonces, commitments) =
+                frost::round1::commit(key_packages[&identifier].signing_share(), &mut rng);
+            nonces_map.insert(identifier, nonces);
+            commitments_map.insert(identifier, commitments);
+        }
+
+        let message = "message to sign".as_bytes();
+        let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+        for (identifier, nonces) in &nonces_map {
+            let key_package = &key_packages[identifier];
+            let signature_share =
+                frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+            let cached_signature_share = frost::round2::sign_cached(
+                &signing_package,
+                nonces,
+                key_package,
+                caches.get_mut(identifier).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(signature_share, cached_signature_share);
+        }
+    }
+}
+
+/// Test that a `KeyPackage` is checked against matching and mismatched
+/// `PublicKeyPackage`s.
+pub fn check_key_package_against_public_package<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let (_other_shares, other_pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let id_1 = Identifier::<C>::try_from(1).unwrap();
+    let id_2 = Identifier::<C>::try_from(2).unwrap();
+
+    for key_package in key_packages.values() {
+        assert!(key_package.check_against_public_package(&pubkeys).is_ok());
+    }
+
+    // Package from another group
+    assert_eq!(
+        key_packages[&id_1].check_against_public_package(&other_pubkeys),
+        Err(Error::InvalidSecretShare { culprit: id_1 })
+    );
+
+    // Verifying share of another participant
+    let mut verifying_shares = pubkeys.verifying_shares().clone();
+    verifying_shares.insert(id_1, pubkeys.verifying_shares()[&id_2]);
+    let swapped_pubkeys =
+        frost::keys::PublicKeyPackage::new(verifying_shares, *pubkeys.verifying_key());
+    assert_eq!(
+        key_packages[&id_1].check_against_public_package(&swapped_pubkeys),
+        Err(Error::InvalidSecretShare { culprit: id_1 })
+    );
+
+    // Mismatched verifying key
+    let wrong_key_pubkeys = frost::keys::PublicKeyPackage::new(
+        pubkeys.verifying_shares().clone(),
+        *other_pubkeys.verifying_key(),
+    );
+    assert_eq!(
+        key_packages[&id_1].check_against_public_package(&wrong_key_pubkeys),
+        Err(Error::MismatchedGroupPublicKey)
+    );
+
+    // Unknown participant
+    let mut verifying_shares = pubkeys.verifying_shares().clone();
+    verifying_shares.remove(&id_1);
+    let missing_pubkeys =
+        frost::keys::PublicKeyPackage::new(verifying_shares, *pubkeys.verifying_key());
+    assert_eq!(
+        key_packages[&id_1].check_against_public_package(&missing_pubkeys),
+        Err(Error::UnknownIdentifier { identifier: id_1 })
+    );
+}
+
+/// Test designated-verifier signing: the signature verifies with the
+/// designated verifier's nonce only, and the verifier can produce valid
+/// signatures on their own.
+pub fn check_sign_designated<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    // The verifier commits to their nonce before round 1.
+    let verifier_nonce = frost::designated::VerifierNonce::<C>::new(&mut rng);
+    let verifier_commitment = verifier_nonce.commit();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    // A nonce not matching the commitment is rejected.
+    let other_verifier_nonce = frost::designated::VerifierNonce::<C>::new(&mut rng);
+    let (identifier, key_package) = key_packages.iter().next().unwrap();
+    assert_eq!(
+        frost::designated::sign(
+            &signing_package,
+            &nonces_map[identifier],
+            key_package,
+            &verifier_commitment,
+            &other_verifier_nonce,
+        ),
+        Err(Error::InvalidVerifierNonce)
+    );
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let signature_share = frost::designated::sign(
+            &signing_package,
+            &nonces_map[identifier],
+            key_package,
+            &verifier_commitment,
+            &verifier_nonce,
+        )
+        .unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let signature = frost::designated::aggregate(
+        &signing_package,
+        &signature_shares,
+        &pubkeys,
+        &verifier_commitment,
+        &verifier_nonce,
+    )
+    .unwrap();
+
+    // Verifies for the designated verifier's transcript only.
+    assert!(frost::designated::verify(
+        pubkeys.verifying_key(),
+        message,
+        &signature,
+        &verifier_nonce
+    )
+    .is_ok());
+    assert_eq!(
+        frost::designated::verify(
+            pubkeys.verifying_key(),
+            message,
+            &signature,
+            &other_verifier_nonce
+        ),
+        Err(Error::InvalidSignature)
+    );
+    assert!(pubkeys.verifying_key().verify(message, &signature).is_err());
+
+    // Non-transferability: the verifier can produce a valid signature without
+    // the signers, from the challenge derived from their nonce.
+    let z = <<C::Group as Group>::Field>::random(&mut rng);
+    let challenge = frost::designated::challenge(pubkeys.verifying_key(), message, &verifier_nonce);
+    let R = <C::Group>::generator() * z - pubkeys.verifying_key().element * challenge.0;
+    let forged_signature = Signature { R, z };
+    assert!(frost::designated::verify(
+        pubkeys.verifying_key(),
+        message,
+        &forged_signature,
+        &verifier_nonce
+    )
+    .is_ok());
+
+    // An invalid share is detected.
+    let mut signature_shares = signature_shares;
+    let invalid_share = signature_shares.get_mut(identifier).unwrap();
+    invalid_share.share = invalid_share.share + <<C::Group as Group>::Field>::one();
+    assert_eq!(
+        frost::designated::aggregate(
+            &signing_package,
+            &signature_shares,
+            &pubkeys,
+            &verifier_commitment,
+            &verifier_nonce,
+        ),
+        Err(Error::InvalidSignatureShares {
+            culprits: BTreeSet::from([*identifier])
+        })
+    );
+}
+
+/// Test that secret shares are the secret polynomial evaluated at the
+/// identifiers as scalars, including for hash-derived identifiers that are
+/// not small integers.
+pub fn check_share_generation_with_derived_identifiers<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let secret = crate::SigningKey::<C>::new(&mut rng);
+
+    let max_signers = 5;
+    let min_signers = 3;
+
+    let coefficients =
+        frost::keys::generate_coefficients::<C, _>(min_signers as usize - 1, &mut rng);
+
+    let identifiers: Vec<_> = ["alice", "bob", "carol", "dave", "eve"]
+        .iter()
+        .map(|name| Identifier::<C>::derive(name.as_bytes()).unwrap())
+        .collect();
+
+    let secret_shares = frost::keys::generate_secret_shares(
+        &secret,
+        max_signers,
+        min_signers,
+        coefficients.clone(),
+        &identifiers,
+    )
+    .unwrap();
+
+    for secret_share in &secret_shares {
+        // f(x) = secret + a_1 * x + a_2 * x^2, evaluated at the identifier.
+        let id = *secret_share.identifier();
+        let expected = secret.scalar + id * (coefficients[0] + id * coefficients[1]);
+        assert!(secret_share.signing_share().0 == expected);
+
+        secret_share.verify().unwrap();
+    }
+
+    let key_packages: Vec<frost::keys::KeyPackage<C>> = secret_shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|s| s.try_into().unwrap())
+        .collect();
+
+    assert_eq!(
+        frost::keys::reconstruct::<C>(&key_packages)
+            .unwrap()
+            .serialize()
+            .as_ref(),
+        secret.serialize().as_ref()
+    );
+}
+
+/// Test verifying signatures given encoded.
+pub fn check_verify_encoded<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let verifying_key = VerifyingKey::<C>::from(&signing_key);
+
+    let message = "message to sign".as_bytes();
+    let signature = signing_key.sign(&mut rng, message);
+    let signature_bytes = signature.serialize().as_ref().to_vec();
+
+    for format in [
+        frost::SignatureFormat::Raw,
+        frost::SignatureFormat::DerIfApplicable,
+    ] {
+        verifying_key
+            .verify_encoded(message, &signature_bytes, format)
+            .unwrap();
+
+        assert_eq!(
+            verifying_key.verify_encoded("wrong message".as_bytes(), &signature_bytes, format),
+            Err(Error::InvalidSignature)
+        );
+
+        // Truncated signature
+        assert_eq!(
+            verifying_key.verify_encoded(
+                message,
+                &signature_bytes[..signature_bytes.len() - 1],
+                format
+            ),
+            Err(Error::MalformedSignature)
+        );
+
+        // Trailing bytes
+        let mut long_signature_bytes = signature_bytes.clone();
+        long_signature_bytes.push(0);
+        assert_eq!(
+            verifying_key.verify_encoded(message, &long_signature_bytes, format),
+            Err(Error::MalformedSignature)
+        );
+    }
+}
+
+/// Test proving possession of signing shares.
+pub fn check_prove_possession<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let nonce = b"audit challenge";
+    let context = Some(&b"health check"[..]);
+
+    for (identifier, key_package) in &key_packages {
+        let proof = key_package.prove_possession(nonce, context, &mut rng);
+        pubkeys.verifying_shares()[identifier]
+            .verify_possession(*identifier, nonce, context, &proof)
+            .unwrap();
+
+        let proof = frost::keys::PossessionProof::<C>::deserialize(proof.serialize()).unwrap();
+        pubkeys.verifying_shares()[identifier]
+            .verify_possession(*identifier, nonce, context, &proof)
+            .unwrap();
+
+        let proof = key_package.prove_possession(nonce, None, &mut rng);
+        pubkeys.verifying_shares()[identifier]
+            .verify_possession(*identifier, nonce, None, &proof)
+            .unwrap();
+    }
+
+    let id_1 = Identifier::<C>::try_from(1).unwrap();
+    let id_2 = Identifier::<C>::try_from(2).unwrap();
+
+    // Proof from the wrong share
+    let proof = key_packages[&id_2].prove_possession(nonce, context, &mut rng);
+    assert_eq!(
+        pubkeys.verifying_shares()[&id_1].verify_possession(id_1, nonce, context, &proof),
+        Err(Error::InvalidProofOfKnowledge { culprit: id_1 })
+    );
+
+    // Proof bound to another identifier
+    let proof = key_packages[&id_1].prove_possession(nonce, context, &mut rng);
+    assert_eq!(
+        pubkeys.verifying_shares()[&id_1].verify_possession(id_2, nonce, context, &proof),
+        Err(Error::InvalidProofOfKnowledge { culprit: id_2 })
+    );
+
+    // Proof replayed under a different nonce
+    assert_eq!(
+        pubkeys.verifying_shares()[&id_1].verify_possession(
+            id_1,
+            b"another challenge",
+            context,
+            &proof
+        ),
+        Err(Error::InvalidProofOfKnowledge { culprit: id_1 })
+    );
+
+    // Proof checked under a different context, or without one
+    assert_eq!(
+        pubkeys.verifying_shares()[&id_1].verify_possession(
+            id_1,
+            nonce,
+            Some(&b"other context"[..]),
+            &proof
+        ),
+        Err(Error::InvalidProofOfKnowledge { culprit: id_1 })
+    );
+    assert_eq!(
+        pubkeys.verifying_shares()[&id_1].verify_possession(id_1, nonce, None, &proof),
+        Err(Error::InvalidProofOfKnowledge { culprit: id_1 })
+    );
+}
+
+/// Test that streaming aggregation over a large signing set produces the same
+/// signature as `aggregate`.
+pub fn check_aggregate_streaming<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 100;
+    let min_signers = 67;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let signature_share =
+            frost::round2::sign(&signing_package, &nonces_map[identifier], key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    let streamed_signature =
+        frost::aggregate_streaming(&signing_package, signature_shares.clone(), &pubkeys).unwrap();
+    assert_eq!(signature, streamed_signature);
+
+    // Missing share
+    let mut other_shares: Vec<_> = signature_shares.into_iter().collect();
+    let (identifier, signature_share) = other_shares.remove(0);
+    assert_eq!(
+        frost::aggregate_streaming(&signing_package, other_shares.clone(), &pubkeys),
+        Err(Error::UnknownIdentifier { identifier })
+    );
+
+    // Duplicated share
+    assert_eq!(
+        frost::aggregate_streaming(
+            &signing_package,
+            other_shares
+                .clone()
+                .into_iter()
+                .chain([(identifier, signature_share), (identifier, signature_share)]),
+            &pubkeys
+        ),
+        Err(Error::UnknownIdentifier { identifier })
+    );
+
+    // Invalid share
+    let mut invalid_share = signature_share;
+    invalid_share.share = invalid_share.share + <<C::Group as Group>::Field>::one();
+    assert_eq!(
+        frost::aggregate_streaming(
+            &signing_package,
+            other_shares
+                .into_iter()
+                .chain([(identifier, invalid_share)]),
+            &pubkeys
+        ),
+        Err(Error::InvalidSignature)
+    );
+}
+
+/// Test that signatures bound to different network ids don't cross-verify.
+pub fn check_sign_with_network_id<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let message = "message to sign".as_bytes();
+    let network_1 = 1u32.to_be_bytes();
+    let network_2 = 2u32.to_be_bytes();
+
+    let mut sign = |network_id: Option<&[u8]>, message: &[u8]| {
+        let mut nonces_map = BTreeMap::new();
+        let mut commitments_map = BTreeMap::new();
+        for (identifier, key_package) in &key_packages {
+            let (nonces, commitments) =
+                frost::round1::commit(key_package.signing_share(), &mut rng);
+            nonces_map.insert(*identifier, nonces);
+            commitments_map.insert(*identifier, commitments);
+        }
+
+        let signing_package = match network_id {
+            Some(network_id) => {
+                frost::SigningPackage::new_with_network_id(commitments_map, network_id, message)
+            }
+            None => frost::SigningPackage::new(commitments_map, message),
+        };
+
+        let mut signature_shares = BTreeMap::new();
+        for (identifier, key_package) in &key_packages {
+            let signature_share =
+                frost::round2::sign(&signing_package, &nonces_map[identifier], key_package)
+                    .unwrap();
+            signature_shares.insert(*identifier, signature_share);
+        }
+
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap()
+    };
+
+    let signature_1 = sign(Some(&network_1), message);
+    let signature_2 = sign(Some(&network_2), message);
+
+    let verifying_key = pubkeys.verifying_key();
+    verifying_key
+        .verify_with_network_id(&network_1, message, &signature_1)
+        .unwrap();
+    verifying_key
+        .verify_with_network_id(&network_2, message, &signature_2)
+        .unwrap();
+
+    assert_eq!(
+        verifying_key.verify_with_network_id(&network_2, message, &signature_1),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verifying_key.verify_with_network_id(&network_1, message, &signature_2),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verifying_key.verify(message, &signature_1),
+        Err(Error::InvalidSignature)
+    );
+
+    // A regular signature over the message prefixed with the network id
+    // doesn't verify under that network id.
+    let mut prefixed_message = b"FROST-network-id".to_vec();
+    prefixed_message.extend_from_slice(&(network_1.len() as u64).to_be_bytes());
+    prefixed_message.extend_from_slice(&network_1);
+    prefixed_message.extend_from_slice(message);
+    let signature = sign(None, &prefixed_message);
+    verifying_key.verify(&prefixed_message, &signature).unwrap();
+    assert_eq!(
+        verifying_key.verify_with_network_id(&network_1, message, &signature),
+        Err(Error::InvalidSignature)
+    );
+}
+
+/// Test restoring a [`KeyPackage`](frost::keys::KeyPackage) from bytes with
+/// `TryFrom<&[u8]>`, and rejecting truncated, extended or inconsistent inputs.
+#[cfg(feature = "serialization")]
+pub fn check_key_package_try_from_bytes<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: Vec<frost::keys::KeyPackage<C>> = shares
+        .into_values()
+        .map(|share| share.try_into().unwrap())
+        .collect();
+
+    for key_package in &key_packages {
+        let bytes = key_package.serialize().unwrap();
+        let restored = frost::keys::KeyPackage::<C>::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(&restored, key_package);
+
+        // Truncated
+        assert_eq!(
+            frost::keys::KeyPackage::<C>::try_from(&bytes[..bytes.len() - 1]),
+            Err(Error::DeserializationError)
+        );
+
+        // Trailing bytes
+        let mut long_bytes = bytes.clone();
+        long_bytes.push(0);
+        assert_eq!(
+            frost::keys::KeyPackage::<C>::try_from(long_bytes.as_slice()),
+            Err(Error::DeserializationError)
+        );
+    }
+
+    // Verifying share not matching the signing share
+    let mut inconsistent = key_packages[0].clone();
+    inconsistent.verifying_share = key_packages[1].verifying_share;
+    let bytes = inconsistent.serialize().unwrap();
+    assert_eq!(
+        frost::keys::KeyPackage::<C>::try_from(bytes.as_slice()),
+        Err(Error::InvalidSecretShare {
+            culprit: inconsistent.identifier
+        })
+    );
+}
+
+/// Test detecting duplicated identifiers.
+pub fn check_identifiers_unique<C: Ciphersuite>() {
+    let id_1 = Identifier::<C>::try_from(1).unwrap();
+    let id_2 = Identifier::<C>::try_from(2).unwrap();
+    let id_3 = Identifier::<C>::derive("username3".as_bytes()).unwrap();
+
+    assert!(Identifier::check_unique(&[id_3, id_1, id_2]).is_ok());
+    assert!(Identifier::<C>::check_unique(&[]).is_ok());
+    assert_eq!(
+        Identifier::check_unique(&[id_3, id_1, id_2, id_1]),
+        Err(Error::DuplicatedIdentifier { identifier: id_1 })
+    );
+
+    let mut sorted = vec![id_1, id_2, id_3];
+    sorted.sort();
+    assert_eq!(
+        Identifier::sorted_unique(&[id_3, id_1, id_2, id_1, id_3]),
+        sorted
+    );
+}
+
+/// Test that packages built from the same data serialize to the same bytes
+/// regardless of the order the participants were inserted in.
+#[cfg(feature = "serialization")]
+pub fn check_serialization_independent_of_insertion_order<
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+>(
+    mut rng: R,
+) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        5,
+        3,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut commitments = Vec::new();
+    for (identifier, share) in &shares {
+        let (_nonces, signing_commitments) = frost::round1::commit(share.signing_share(), &mut rng);
+        commitments.push((*identifier, signing_commitments));
+    }
+    let verifying_shares: Vec<_> = pubkeys
+        .verifying_shares()
+        .iter()
+        .map(|(identifier, verifying_share)| (*identifier, *verifying_share))
+        .collect();
+
+    let message = "message to sign".as_bytes();
+    let build = |commitments: Vec<_>, verifying_shares: Vec<_>| {
+        let mut commitments_map = BTreeMap::new();
+        for (identifier, signing_commitments) in commitments {
+            commitments_map.insert(identifier, signing_commitments);
+        }
+        let mut verifying_shares_map = BTreeMap::new();
+        for (identifier, verifying_share) in verifying_shares {
+            verifying_shares_map.insert(identifier, verifying_share);
+        }
+        (
+            frost::SigningPackage::new(commitments_map, message)
+                .serialize()
+                .unwrap(),
+            PublicKeyPackage::new(verifying_shares_map, *pubkeys.verifying_key())
+                .serialize()
+                .unwrap(),
+        )
+    };
+
+    let in_order = build(commitments.clone(), verifying_shares.clone());
+    let reversed = build(
+        commitments.iter().rev().cloned().collect(),
+        verifying_shares.iter().rev().cloned().collect(),
+    );
+    assert_eq!(in_order, reversed);
+}
+
+/// Test that the coordinator can reject commitments that are not valid group
+/// elements when creating the signing package.
+pub fn check_signing_package_new_checked<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, share) in shares.iter().take(min_signers as usize) {
+        let (_nonces, commitments) = frost::round1::commit(share.signing_share(), &mut rng);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let message = "message to sign".as_bytes();
+
+    // Well-formed commitments are accepted.
+    let signing_package =
+        frost::SigningPackage::new_checked(commitments_map.clone(), message).unwrap();
+    assert_eq!(
+        signing_package,
+        frost::SigningPackage::new(commitments_map.clone(), message)
+    );
+
+    // An identity commitment is rejected and attributed to its sender.
+    let culprit = *commitments_map.keys().nth(1).unwrap();
+    commitments_map.get_mut(&culprit).unwrap().binding =
+        frost::round1::NonceCommitment(<C::Group>::identity());
+    assert_eq!(
+        frost::SigningPackage::new_checked(commitments_map, message),
+        Err(Error::InvalidCommitment { culprit })
+    );
+}
+
+/// Test that signing with split nonces produces the same share as signing
+/// with the unified nonces.
+pub fn check_sign_split<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments_map, b"message to sign");
+
+    for (identifier, key_package) in &key_packages {
+        let nonces = &nonces_map[identifier];
+        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+
+        // Store each half separately and restore them independently.
+        let split_nonces = frost::round1::SplitNonces::from(nonces.clone());
+        let hiding_bytes = split_nonces.hiding().serialize();
+        let binding_bytes = split_nonces.binding().serialize();
+        let commitments = *split_nonces.commitments();
+        let hiding = frost::round1::Nonce::deserialize(hiding_bytes).unwrap();
+        let binding = frost::round1::Nonce::deserialize(binding_bytes).unwrap();
+
+        let split_signature_share = frost::round2::sign_split(
+            &signing_package,
+            &hiding,
+            &binding,
+            &commitments,
+            key_package,
+        )
+        .unwrap();
+        assert!(split_signature_share == signature_share);
+
+        // Swapped halves don't match their commitments.
+        assert_eq!(
+            frost::round2::sign_split(
+                &signing_package,
+                &binding,
+                &hiding,
+                &commitments,
+                key_package,
+            ),
+            Err(Error::IncorrectCommitment)
+        );
+        assert!(frost::round1::SplitNonces::new(binding, hiding, commitments).is_err());
+    }
+}
+
+/// Test reconstructing the secret from secret shares.
+pub fn check_reconstruct_secret<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let secret = crate::SigningKey::<C>::new(&mut rng);
+
+    let max_signers = 5;
+    let min_signers = 3;
+
+    let (shares, _pubkeys) = frost::keys::split(
+        &secret,
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let secret_shares: Vec<_> = shares.into_values().collect();
+
+    // Any `min_signers` shares, or more, reconstruct the secret.
+    for shares in [&secret_shares[..3], &secret_shares[2..], &secret_shares[..]] {
+        assert_eq!(
+            frost::keys::reconstruct_secret::<C>(shares)
+                .unwrap()
+                .serialize()
+                .as_ref(),
+            secret.serialize().as_ref()
+        );
+    }
+
+    // Test error cases
+
+    assert_eq!(
+        frost::keys::reconstruct_secret::<C>(&[]).unwrap_err(),
+        Error::IncorrectNumberOfShares
+    );
+
+    assert_eq!(
+        frost::keys::reconstruct_secret::<C>(&secret_shares[..2]).unwrap_err(),
+        Error::IncorrectNumberOfShares
+    );
+
+    let mut duplicated = secret_shares[..3].to_vec();
+    duplicated[0] = duplicated[1].clone();
+    assert_eq!(
+        frost::keys::reconstruct_secret::<C>(&duplicated).unwrap_err(),
+        Error::DuplicatedIdentifier {
+            identifier: *duplicated[1].identifier()
+        }
+    );
+
+    // A share from a different sharing of the same secret has a different
+    // commitment.
+    let (other_shares, _pubkeys) = frost::keys::split(
+        &secret,
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let mut mixed = secret_shares[..3].to_vec();
+    mixed[0] = other_shares[mixed[0].identifier()].clone();
+    assert_eq!(
+        frost::keys::reconstruct_secret::<C>(&mixed).unwrap_err(),
+        Error::MismatchedCommitments
+    );
+
+    let mut corrupted = secret_shares[..3].to_vec();
+    corrupted[0].signing_share.0 =
+        corrupted[0].signing_share.0 + <<C::Group as Group>::Field>::one();
+    assert_eq!(
+        frost::keys::reconstruct_secret::<C>(&corrupted).unwrap_err(),
+        Error::InvalidSecretShare {
+            culprit: *corrupted[0].identifier()
+        }
+    );
+}
+
+/// Test threshold ElGamal decryption with the key shares.
+pub fn check_threshold_decryption<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let plaintext = <C::Group>::generator() * <<C::Group as Group>::Field>::random(&mut rng);
+    let ciphertext =
+        frost::decrypt::Ciphertext::encrypt(pubkeys.verifying_key(), &plaintext, &mut rng);
+
+    // Any set of at least `min_signers` participants can decrypt.
+    for decryptors in [
+        key_packages
+            .keys()
+            .take(3)
+            .copied()
+            .collect::<BTreeSet<_>>(),
+        key_packages.keys().skip(2).copied().collect(),
+        key_packages.keys().copied().collect(),
+    ] {
+        let decryption_shares: BTreeMap<_, _> = decryptors
+            .iter()
+            .map(|identifier| {
+                let share = frost::decrypt::decryption_share(
+                    &ciphertext,
+                    &key_packages[identifier],
+                    &decryptors,
+                )
+                .unwrap();
+                (*identifier, share)
+            })
+            .collect();
+        let decrypted =
+            frost::decrypt::combine_decryption_shares(&ciphertext, &decryption_shares).unwrap();
+        assert!(decrypted == plaintext);
+    }
+
+    // Too few decryptors.
+    let decryptors: BTreeSet<_> = key_packages.keys().take(2).copied().collect();
+    let identifier = decryptors.first().unwrap();
+    assert_eq!(
+        frost::decrypt::decryption_share(&ciphertext, &key_packages[identifier], &decryptors),
+        Err(Error::IncorrectNumberOfShares)
+    );
+
+    // Participant not among the decryptors.
+    let outsider = key_packages.keys().last().unwrap();
+    assert_eq!(
+        frost::decrypt::decryption_share(&ciphertext, &key_packages[outsider], &decryptors),
+        Err(Error::UnknownIdentifier {
+            identifier: *outsider
+        })
+    );
+}
+
+/// Test looking up verifying shares in a PublicKeyPackage.
+pub fn check_public_key_package_verifying_share<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    for (identifier, share) in &shares {
+        let (verifying_share, _) = share.verify().unwrap();
+        assert_eq!(pubkeys.verifying_share(identifier), Some(&verifying_share));
+    }
+
+    let outsider = Identifier::try_from(max_signers + 1).unwrap();
+    assert_eq!(pubkeys.verifying_share(&outsider), None);
+
+    // Iteration is in ascending order of identifiers.
+    let identifiers: Vec<_> = pubkeys.verifying_shares().keys().copied().collect();
+    let mut sorted = identifiers.clone();
+    sorted.sort();
+    assert_eq!(identifiers, sorted);
+}
+
+/// Test exporting a public key package as verification tuples and importing
+/// it back.
+pub fn check_public_key_package_verification_tuples<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (_shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let tuples = pubkeys.verification_tuples();
+    assert_eq!(tuples.len(), max_signers as usize);
+    let identifiers: Vec<_> = tuples.iter().map(|(identifier, _)| *identifier).collect();
+    let mut sorted = identifiers.clone();
+    sorted.sort();
+    assert_eq!(identifiers, sorted);
+
+    // The order of the tuples doesn't matter when importing.
+    let imported = frost::keys::PublicKeyPackage::from_verification_tuples(
+        tuples.iter().rev().copied(),
+        *pubkeys.verifying_key(),
+    )
+    .unwrap();
+    assert_eq!(imported, pubkeys);
+
+    let mut duplicated = tuples.clone();
+    duplicated.push(tuples[0]);
+    assert_eq!(
+        frost::keys::PublicKeyPackage::from_verification_tuples(
+            duplicated,
+            *pubkeys.verifying_key()
+        ),
+        Err(Error::DuplicatedIdentifier {
+            identifier: tuples[0].0
+        })
+    );
+}
+
+/// Test that a signing package with a commitment from outside the group is
+/// detected before signing.
+pub fn check_signing_package_against_public_package<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, share) in shares.iter().take(min_signers as usize) {
+        let (_nonces, commitments) = frost::round1::commit(share.signing_share(), &mut rng);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let message = "message to sign".as_bytes();
+
+    let signing_package = frost::SigningPackage::new(commitments_map.clone(), message);
+    signing_package
+        .check_against_public_package(&pubkeys)
+        .unwrap();
+
+    // Add a commitment for an identifier that is not in the group.
+    let stray = Identifier::try_from(max_signers + 1).unwrap();
+    let (_nonces, commitments) =
+        frost::round1::commit(shares.values().next().unwrap().signing_share(), &mut rng);
+    commitments_map.insert(stray, commitments);
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    assert_eq!(
+        signing_package.check_against_public_package(&pubkeys),
+        Err(Error::UnknownIdentifier { identifier: stray })
+    );
+}
+
+/// Test generating shares from a fully specified polynomial.
+pub fn check_keygen_from_coefficients<C: Ciphersuite>() {
+    let scalar = |n: u16| {
+        (0..n).fold(<<C::Group as Group>::Field>::zero(), |acc, _| {
+            acc + <<C::Group as Group>::Field>::one()
+        })
+    };
+    let identifiers: Vec<Identifier<C>> = (1..=4u16).map(|i| i.try_into().unwrap()).collect();
+
+    // f(x) = 5 + 3x + 2x^2
+    let coefficients = [scalar(5), scalar(3), scalar(2)];
+    let (shares, pubkeys) =
+        frost::keys::keygen_from_coefficients::<C>(&coefficients, &identifiers).unwrap();
+
+    for (identifier, expected) in identifiers.iter().zip([10, 19, 32, 49]) {
+        let share = &shares[identifier];
+        assert!(share.signing_share().0 == scalar(expected));
+        assert_eq!(share.commitment().coefficients().len(), 3);
+        share.verify().unwrap();
+    }
+    assert!(pubkeys.verifying_key().element == <C::Group>::generator() * scalar(5));
+
+    // Test error cases
+
+    assert_eq!(
+        frost::keys::keygen_from_coefficients::<C>(&coefficients[..1], &identifiers).unwrap_err(),
+        Error::InvalidMinSigners
+    );
+    assert_eq!(
+        frost::keys::keygen_from_coefficients::<C>(&coefficients, &identifiers[..2]).unwrap_err(),
+        Error::InvalidMinSigners
+    );
+    assert_eq!(
+        frost::keys::keygen_from_coefficients::<C>(&[scalar(0), scalar(3)], &identifiers)
+            .unwrap_err(),
+        Error::MalformedSigningKey
+    );
+    assert_eq!(
+        frost::keys::keygen_from_coefficients::<C>(&[scalar(5), scalar(0)], &identifiers)
+            .unwrap_err(),
+        Error::InvalidCoefficients
+    );
+    assert_eq!(
+        frost::keys::keygen_from_coefficients::<C>(
+            &coefficients,
+            &[identifiers[0], identifiers[1], identifiers[0]]
+        )
+        .unwrap_err(),
+        Error::DuplicatedIdentifier {
+            identifier: identifiers[0]
+        }
+    );
+}
+
+/// Test checking a KeyPackage against the commitment of its secret share.
+pub fn check_key_package_verify_against_commitment<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    for share in shares.values() {
+        let key_package = frost::keys::KeyPackage::try_from(share.clone()).unwrap();
+        key_package
+            .verify_against_commitment(share.commitment())
+            .unwrap();
+    }
+
+    let mut shares = shares.into_values();
+    let share = shares.next().unwrap();
+    let other_share = shares.next().unwrap();
+    let key_package = frost::keys::KeyPackage::try_from(share.clone()).unwrap();
+
+    // The verifying share was replaced with another participant's.
+    let tampered = frost::keys::KeyPackage::new(
+        *key_package.identifier(),
+        *key_package.signing_share(),
+        pubkeys.verifying_shares()[other_share.identifier()],
+        *key_package.verifying_key(),
+        *key_package.min_signers(),
+    );
+    assert_eq!(
+        tampered.verify_against_commitment(share.commitment()),
+        Err(Error::InvalidSecretShare {
+            culprit: *key_package.identifier()
+        })
+    );
+
+    // The verifying key was replaced.
+    let tampered = frost::keys::KeyPackage::new(
+        *key_package.identifier(),
+        *key_package.signing_share(),
+        *key_package.verifying_share(),
+        VerifyingKey::from(&SigningKey::new(&mut rng)),
+        *key_package.min_signers(),
+    );
+    assert_eq!(
+        tampered.verify_against_commitment(share.commitment()),
+        Err(Error::MismatchedGroupPublicKey)
+    );
+}
+
+/// Test signing with key packages held by a single process.
+pub fn check_sign_custodial<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: Vec<frost::keys::KeyPackage<C>> = shares
+        .into_values()
+        .map(|share| share.try_into().unwrap())
+        .collect();
+
+    let message = "message to sign".as_bytes();
+    let signature =
+        frost::keys::sign_custodial(&key_packages[..min_signers as usize], message, &mut rng)
+            .unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+
+    // Nonces are fresh for every signature.
+    let other_signature =
+        frost::keys::sign_custodial(&key_packages[..min_signers as usize], message, &mut rng)
+            .unwrap();
+    assert!(signature.R != other_signature.R);
+
+    // Test error cases
+
+    assert_eq!(
+        frost::keys::sign_custodial(&key_packages[..2], message, &mut rng),
+        Err(Error::IncorrectNumberOfShares)
+    );
+
+    let duplicated = [
+        key_packages[0].clone(),
+        key_packages[1].clone(),
+        key_packages[0].clone(),
+    ];
+    assert_eq!(
+        frost::keys::sign_custodial(&duplicated, message, &mut rng),
+        Err(Error::DuplicatedIdentifier {
+            identifier: *key_packages[0].identifier()
+        })
+    );
+
+    let (other_shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let mut mixed = key_packages[..min_signers as usize].to_vec();
+    mixed[0] = other_shares[mixed[0].identifier()]
+        .clone()
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        frost::keys::sign_custodial(&mixed, message, &mut rng),
+        Err(Error::MismatchedGroupPublicKey)
+    );
+}
+
+/// Test that identical commitments from distinct participants are rejected.
+pub fn check_sign_with_identical_commitments<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<frost::Identifier<C>, frost::keys::KeyPackage<C>> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(k, v)| (k, frost::keys::KeyPackage::try_from(v).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let message = "message to sign".as_bytes();
+    let (id_1, id_2) = {
+        let mut ids = key_packages.keys();
+        (*ids.next().unwrap(), *ids.next().unwrap())
+    };
+
+    // Distinct commitments pass.
+    let signing_package =
+        frost::SigningPackage::new_checked(commitments_map.clone(), message).unwrap();
+    frost::round2::sign(&signing_package, &nonces_map[&id_1], &key_packages[&id_1]).unwrap();
+
+    // The second participant sends the same commitments as the first.
+    commitments_map.insert(id_2, commitments_map[&id_1]);
+    assert_eq!(
+        frost::SigningPackage::new_checked(commitments_map.clone(), message),
+        Err(Error::SuspiciousCommitments)
+    );
+    let signing_package = frost::SigningPackage::new(commitments_map.clone(), message);
+    assert_eq!(
+        frost::round2::sign(&signing_package, &nonces_map[&id_1], &key_packages[&id_1]),
+        Err(Error::SuspiciousCommitments)
+    );
+
+    // Sharing a single nonce commitment is detected too.
+    let (_nonces, mut commitments) =
+        frost::round1::commit(key_packages[&id_2].signing_share(), &mut rng);
+    commitments.binding = commitments_map[&id_1].hiding;
+    commitments_map.insert(id_2, commitments);
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    assert_eq!(
+        frost::round2::sign(&signing_package, &nonces_map[&id_1], &key_packages[&id_1]),
+        Err(Error::SuspiciousCommitments)
+    );
+}
+
+/// Test that the group id only depends on the contents of the
+/// PublicKeyPackage.
+pub fn check_public_key_package_group_id<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (_shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    // Rebuilding the package with the shares inserted in a different order
+    // gives the same id.
+    let reversed: BTreeMap<_, _> = pubkeys
+        .verifying_shares()
+        .iter()
+        .rev()
+        .map(|(k, v)| (*k, *v))
+        .collect();
+    let rebuilt = frost::keys::PublicKeyPackage::new(reversed, *pubkeys.verifying_key());
+    assert_eq!(pubkeys.group_id(), rebuilt.group_id());
+
+    #[cfg(feature = "serialization")]
+    {
+        let deserialized =
+            frost::keys::PublicKeyPackage::<C>::deserialize(&pubkeys.serialize().unwrap()).unwrap();
+        assert_eq!(pubkeys.group_id(), deserialized.group_id());
+    }
+
+    // A different group has a different id.
+    let (_shares, other_pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    assert_ne!(pubkeys.group_id(), other_pubkeys.group_id());
+
+    // Dropping a participant changes the id.
+    let mut fewer = pubkeys.verifying_shares().clone();
+    fewer.pop_last();
+    let fewer = frost::keys::PublicKeyPackage::new(fewer, *pubkeys.verifying_key());
+    assert_ne!(pubkeys.group_id(), fewer.group_id());
+}
+
+/// Test building a [`SigningPackage`] incrementally from commitments received
+/// in a random order.
+pub fn check_signing_package_builder<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let message = b"message to sign";
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut commitments: Vec<_> = shares
+        .iter()
+        .map(|(identifier, share)| {
+            let (_nonces, commitments) = frost::round1::commit(share.signing_share(), &mut rng);
+            (*identifier, commitments)
+        })
+        .collect();
+    for i in (1..commitments.len()).rev() {
+        commitments.swap(i, rng.next_u32() as usize % (i + 1));
+    }
+
+    let mut builder = frost::SigningPackageBuilder::new(min_signers);
+    assert!(builder.is_empty());
+    for (i, (identifier, signing_commitments)) in commitments.iter().enumerate() {
+        if i == min_signers as usize - 1 {
+            assert_eq!(builder.build(), Err(Error::MissingMessage));
+            builder.set_message(message);
+            assert_eq!(
+                builder.build(),
+                Err(Error::TooFewCommitments {
+                    have: i,
+                    need: min_signers as usize,
+                })
+            );
+        }
+        builder
+            .add_commitment(*identifier, *signing_commitments)
+            .unwrap();
+    }
+    assert!(builder.is_complete());
+    assert_eq!(builder.len(), max_signers as usize);
+
+    // Adding the commitments of a participant again is rejected.
+    let (identifier, signing_commitments) = commitments[0];
+    assert_eq!(
+        builder.add_commitment(identifier, signing_commitments),
+        Err(Error::DuplicatedIdentifier { identifier })
+    );
+
+    let signing_package = builder.build().unwrap();
+    assert_eq!(
+        signing_package,
+        frost::SigningPackage::new(commitments.into_iter().collect(), message)
+    );
+}
+
+/// Test that refreshed shares are new shares of the same group key.
+pub fn check_refresh_shares<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let shares: Vec<_> = shares.into_values().collect();
+
+    let (refreshed, refreshed_pubkeys) = frost::keys::refresh_shares(&shares, &mut rng).unwrap();
+    let refreshed: Vec<_> = refreshed.into_values().collect();
+    assert_eq!(refreshed_pubkeys.verifying_key(), pubkeys.verifying_key());
+    for (share, refreshed_share) in shares.iter().zip(&refreshed) {
+        assert_eq!(share.identifier(), refreshed_share.identifier());
+        assert_ne!(share.signing_share(), refreshed_share.signing_share());
+        refreshed_share.verify().unwrap();
+    }
+
+    // Any quorum of refreshed shares reconstructs the same secret.
+    let secret = frost::keys::reconstruct_secret(&shares[..3]).unwrap();
+    assert_eq!(
+        frost::keys::reconstruct_secret(&refreshed[..3]).unwrap(),
+        secret
+    );
+    assert_eq!(
+        frost::keys::reconstruct_secret(&refreshed[2..]).unwrap(),
+        secret
+    );
+
+    // The refreshed shares can sign for the group.
+    let key_packages: Vec<_> = refreshed[1..4]
+        .iter()
+        .map(|s| frost::keys::KeyPackage::try_from(s.clone()).unwrap())
+        .collect();
+    let message = b"message to sign";
+    let signature = frost::keys::sign_custodial(&key_packages, message, &mut rng).unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+
+    // Old and refreshed shares can't be mixed.
+    let mixed = [
+        shares[0].clone(),
+        refreshed[1].clone(),
+        refreshed[2].clone(),
+    ];
+    assert_eq!(
+        frost::keys::reconstruct_secret(&mixed),
+        Err(Error::MismatchedCommitments)
+    );
+}
+
+/// Test that the number of commitments of a signing package is checked
+/// against both `min_signers` and `max_signers`.
+pub fn check_signing_package_num_commitments<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let commitments: BTreeMap<_, _> = shares
+        .iter()
+        .map(|(identifier, share)| {
+            let (_nonces, commitments) = frost::round1::commit(share.signing_share(), &mut rng);
+            (*identifier, commitments)
+        })
+        .collect();
+    let max_signers = pubkeys.verifying_shares().len() as u16;
+    let message = b"message to sign";
+
+    let package_of = |n: usize| {
+        frost::SigningPackage::new(commitments.clone().into_iter().take(n).collect(), message)
+    };
+
+    assert_eq!(
+        package_of(2).check_num_commitments(min_signers, max_signers),
+        Err(Error::TooFewCommitments { have: 2, need: 3 })
+    );
+    for n in 3..=5 {
+        assert_eq!(
+            package_of(n).check_num_commitments(min_signers, max_signers),
+            Ok(())
+        );
+    }
+
+    // A group of 4 can't have 5 commitments.
+    assert_eq!(
+        package_of(5).check_num_commitments(min_signers, 4),
+        Err(Error::TooManyCommitments { have: 5, max: 4 })
+    );
+}
+
+/// Test the negation of scalars and elements.
+pub fn check_negate<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    type F<S> = <<S as Ciphersuite>::Group as Group>::Field;
+
+    let zero = F::<C>::zero();
+    let identity = <C::Group>::identity();
+    assert!(F::<C>::negate(&zero) == zero);
+    assert!(<C::Group>::negate(&identity) == identity);
+
+    for _ in 0..8 {
+        let x = F::<C>::random(&mut rng);
+        assert!(F::<C>::negate(&F::<C>::negate(&x)) == x);
+        assert!(x + F::<C>::negate(&x) == zero);
+
+        let element = <C::Group>::generator() * x;
+        assert!(<C::Group>::negate(&<C::Group>::negate(&element)) == element);
+        assert!(element + <C::Group>::negate(&element) == identity);
+        assert!(<C::Group>::negate(&element) == <C::Group>::generator() * F::<C>::negate(&x));
+    }
+}
+
+/// Test that a [`VerifierReplayCache`](frost::replay::VerifierReplayCache)
+/// accepts a signature once per group and message.
+pub fn check_verifier_replay_cache<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        3,
+        2,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let shares: Vec<_> = shares.into_values().collect();
+    let key_packages: Vec<_> = shares
+        .iter()
+        .take(2)
+        .map(|share| frost::keys::KeyPackage::try_from(share.clone()).unwrap())
+        .collect();
+    let message = b"message to sign";
+    let signature = frost::keys::sign_custodial(&key_packages, message, &mut rng).unwrap();
+
+    let mut cache = frost::replay::VerifierReplayCache::new();
+    assert!(cache.is_empty());
+    cache.verify_once(&pubkeys, message, &signature).unwrap();
+    assert_eq!(
+        cache.verify_once(&pubkeys, message, &signature),
+        Err(Error::ReplayDetected)
+    );
+
+    // Even a different signature on the same message is a replay.
+    let other_signature = frost::keys::sign_custodial(&key_packages, message, &mut rng).unwrap();
+    assert_eq!(
+        cache.verify_once(&pubkeys, message, &other_signature),
+        Err(Error::ReplayDetected)
+    );
+
+    // Refreshing the shares changes the verifying shares but not the
+    // verifying key, so it is still a replay.
+    let (_, refreshed_pubkeys) = frost::keys::refresh_shares(&shares, &mut rng).unwrap();
+    assert_eq!(refreshed_pubkeys.verifying_key(), pubkeys.verifying_key());
+    assert_ne!(refreshed_pubkeys.group_id(), pubkeys.group_id());
+    assert_eq!(
+        cache.verify_once(&refreshed_pubkeys, message, &signature),
+        Err(Error::ReplayDetected)
+    );
+
+    // An invalid signature is not recorded.
+    let other_message = b"another message";
+    assert_eq!(
+        cache.verify_once(&pubkeys, other_message, &signature),
+        Err(Error::InvalidSignature)
+    );
+    let signature = frost::keys::sign_custodial(&key_packages, other_message, &mut rng).unwrap();
+    cache
+        .verify_once(&pubkeys, other_message, &signature)
+        .unwrap();
+    assert_eq!(cache.len(), 2);
+
+    #[cfg(feature = "serialization")]
+    {
+        let mut restored =
+            frost::replay::VerifierReplayCache::<C>::deserialize(&cache.serialize().unwrap())
+                .unwrap();
+        assert_eq!(restored, cache);
+        assert_eq!(
+            restored.verify_once(&pubkeys, other_message, &signature),
+            Err(Error::ReplayDetected)
+        );
+    }
+}
+
+/// Test that aggregating with the participants' group commitment shares
+/// detects a participant who used other nonces than they committed to.
+pub fn check_aggregate_with_commitment_shares<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        3,
+        2,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(id, share)| (id, frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (signer_nonces, signer_commitments) =
+            frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces.insert(*id, signer_nonces);
+        commitments.insert(*id, signer_commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments, b"message to sign");
+
+    let mut commitment_shares = BTreeMap::new();
+    let mut signature_shares = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        commitment_shares.insert(
+            *id,
+            frost::round2::group_commitment_share(&signing_package, &nonces[id], key_package)
+                .unwrap(),
+        );
+        signature_shares.insert(
+            *id,
+            frost::round2::sign(&signing_package, &nonces[id], key_package).unwrap(),
+        );
+    }
+
+    let signature = frost::aggregate_with_commitment_shares(
+        &signing_package,
+        &commitment_shares,
+        &signature_shares,
+        &pubkeys,
+    )
+    .unwrap();
+    assert_eq!(
+        signature,
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap()
+    );
+
+    // A participant who used other nonces than they committed to is blamed.
+    let (&cheater, key_package) = key_packages.iter().next().unwrap();
+    let (other_nonces, _) = frost::round1::commit(key_package.signing_share(), &mut rng);
+    let mut inconsistent_shares = commitment_shares.clone();
+    inconsistent_shares.insert(
+        cheater,
+        frost::round2::group_commitment_share(&signing_package, &other_nonces, key_package)
+            .unwrap(),
+    );
+    assert_eq!(
+        frost::aggregate_with_commitment_shares(
+            &signing_package,
+            &inconsistent_shares,
+            &signature_shares,
+            &pubkeys,
+        ),
+        Err(Error::InconsistentCommitmentShare { culprit: cheater })
+    );
+
+    // So is a participant who didn't report their commitment share.
+    let mut missing_shares = commitment_shares;
+    missing_shares.remove(&cheater);
+    assert_eq!(
+        frost::aggregate_with_commitment_shares(
+            &signing_package,
+            &missing_shares,
+            &signature_shares,
+            &pubkeys,
+        ),
+        Err(Error::InconsistentCommitmentShare { culprit: cheater })
+    );
+}
+
+/// Test that aggregating with the digests of the signing packages the
+/// participants signed detects a share computed over a different message.
+pub fn check_aggregate_with_signing_package_digests<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        3,
+        2,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(id, share)| (id, frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (signer_nonces, signer_commitments) =
+            frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces.insert(*id, signer_nonces);
+        commitments.insert(*id, signer_commitments);
+    }
+    let signing_package = frost::SigningPackage::new(commitments.clone(), b"message to sign");
+    let other_package = frost::SigningPackage::new(commitments, b"another message");
+    assert_ne!(signing_package.digest(), other_package.digest());
+
+    let mut digests = BTreeMap::new();
+    let mut signature_shares = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        digests.insert(*id, signing_package.digest());
+        signature_shares.insert(
+            *id,
+            frost::round2::sign(&signing_package, &nonces[id], key_package).unwrap(),
+        );
+    }
+
+    let signature = frost::aggregate_with_signing_package_digests(
+        &signing_package,
+        &digests,
+        &signature_shares,
+        &pubkeys,
+    )
+    .unwrap();
+    assert_eq!(
+        signature,
+        frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap()
+    );
+
+    // A participant who signed a different message is blamed.
+    let (&culprit, key_package) = key_packages.iter().next().unwrap();
+    let mut mismatched_digests = digests.clone();
+    mismatched_digests.insert(culprit, other_package.digest());
+    let mut mismatched_shares = signature_shares.clone();
+    mismatched_shares.insert(
+        culprit,
+        frost::round2::sign(&other_package, &nonces[&culprit], key_package).unwrap(),
+    );
+    assert_eq!(
+        frost::aggregate_with_signing_package_digests(
+            &signing_package,
+            &mismatched_digests,
+            &mismatched_shares,
+            &pubkeys,
+        ),
+        Err(Error::SigningPackageMismatch { culprit })
+    );
+
+    // So is a participant who didn't report a digest.
+    let mut missing_digests = digests;
+    missing_digests.remove(&culprit);
+    assert_eq!(
+        frost::aggregate_with_signing_package_digests(
+            &signing_package,
+            &missing_digests,
+            &signature_shares,
+            &pubkeys,
+        ),
+        Err(Error::SigningPackageMismatch { culprit })
+    );
+}
+
+/// Test round-tripping a signing package and a signature share through each
+/// enabled codec, and that decoding the output of a codec with another one
+/// fails cleanly.
+///
+/// The `Json`, `Cbor` and `MessagePack` codecs are only checked when their
+/// features are enabled, which the frost-ristretto255 tests do.
+#[cfg(feature = "serialization")]
+pub fn check_codecs<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        3,
+        2,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let (identifier, share) = shares.into_iter().next().unwrap();
+    let key_package = frost::keys::KeyPackage::try_from(share).unwrap();
+    let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+    let signing_package =
+        frost::SigningPackage::new(BTreeMap::from([(identifier, commitments)]), b"message");
+    let signature_share = frost::round2::sign(&signing_package, &nonces, &key_package).unwrap();
+
+    let mut encodings = vec![check_codec::<C, frost::codec::Postcard>(
+        &signing_package,
+        &signature_share,
+    )];
+    #[cfg(feature = "json")]
+    encodings.push(check_codec::<C, frost::codec::Json>(
+        &signing_package,
+        &signature_share,
+    ));
+    #[cfg(feature = "cbor")]
+    encodings.push(check_codec::<C, frost::codec::Cbor>(
+        &signing_package,
+        &signature_share,
+    ));
+    #[cfg(feature = "msgpack")]
+    encodings.push(check_codec::<C, frost::codec::MessagePack>(
+        &signing_package,
+        &signature_share,
+    ));
+
+    // The postcard codec is the encoding used by the structs' own methods.
+    assert_eq!(encodings[0].0, signing_package.serialize().unwrap());
+
+    for (i, (bytes, _)) in encodings.iter().enumerate() {
+        for (j, (_, decode)) in encodings.iter().enumerate() {
+            if i != j {
+                assert_eq!(decode(bytes), Err(Error::DeserializationError));
+            }
+        }
+    }
+}
+
+/// Round-trips a signing package and a signature share through the codec
+/// `K`, returning the encoded signing package and the codec's decoder.
+#[cfg(feature = "serialization")]
+fn check_codec<C: Ciphersuite, K: frost::codec::Codec<C>>(
+    signing_package: &frost::SigningPackage<C>,
+    signature_share: &frost::round2::SignatureShare<C>,
+) -> (
+    Vec<u8>,
+    fn(&[u8]) -> Result<frost::SigningPackage<C>, Error<C>>,
+) {
+    let bytes = K::encode(signing_package).unwrap();
+    assert_eq!(
+        &K::decode::<frost::SigningPackage<C>>(&bytes).unwrap(),
+        signing_package
+    );
+
+    let share_bytes = K::encode(signature_share).unwrap();
+    assert_eq!(
+        &K::decode::<frost::round2::SignatureShare<C>>(&share_bytes).unwrap(),
+        signature_share
+    );
+
+    // Truncated encodings are rejected.
+    assert_eq!(
+        K::decode::<frost::SigningPackage<C>>(&bytes[..bytes.len() - 1]),
+        Err(Error::DeserializationError)
+    );
+
+    (bytes, K::decode::<frost::SigningPackage<C>>)
+}
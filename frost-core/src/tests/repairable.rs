@@ -1,6 +1,6 @@
 //! Test for Repairable Threshold Scheme
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use debugless_unwrap::DebuglessUnwrap;
 use rand_core::{CryptoRng, RngCore};
@@ -240,3 +240,180 @@ pub fn check_repair_share_step_1_fails_with_invalid_min_signers<
     assert!(out.is_err());
     assert!(out == Err(Error::InvalidMinSigners))
 }
+
+/// Runs the repair protocol for the share of `lost_id` with the given helpers,
+/// and checks that the repaired share is the original one and can be used to
+/// sign.
+///
+/// If `malicious_id` is given, that helper corrupts one of the deltas it
+/// sends. Since the simulation sees all deltas, it checks each helper's
+/// deltas against their verifying share (which a deployment would do with
+/// commitments to the deltas) and returns an [`Error::InvalidSecretShare`]
+/// naming the first helper whose deltas don't match.
+fn simulate_repair_with<C: Ciphersuite, R: RngCore + CryptoRng>(
+    max_signers: u16,
+    min_signers: u16,
+    lost_id: u16,
+    helper_ids: &[u16],
+    malicious_id: Option<u16>,
+    mut rng: R,
+) -> Result<(), Error<C>> {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer::<C, _>(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )?;
+
+    let participant = Identifier::try_from(lost_id)?;
+    let helpers = helper_ids
+        .iter()
+        .map(|id| Identifier::try_from(*id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let malicious = malicious_id.map(Identifier::try_from).transpose()?;
+
+    // The participant loses their share.
+    let mut shares = shares;
+    let lost_share = shares
+        .remove(&participant)
+        .ok_or(Error::UnknownIdentifier {
+            identifier: participant,
+        })?;
+
+    // Step 1: each helper computes a delta for each helper.
+    let mut deltas = BTreeMap::new();
+    for helper in &helpers {
+        let share = shares.get(helper).ok_or(Error::UnknownIdentifier {
+            identifier: *helper,
+        })?;
+        let mut helper_deltas = repair_share_step_1(&helpers, share, &mut rng, participant)?;
+        if Some(*helper) == malicious {
+            let delta = helper_deltas
+                .values_mut()
+                .next()
+                .ok_or(Error::IncorrectNumberOfIdentifiers)?;
+            *delta = *delta + <<C::Group as Group>::Field>::one();
+        }
+        deltas.insert(*helper, helper_deltas);
+    }
+
+    // The deltas of each helper must add up to their share multiplied by
+    // their Lagrange coefficient.
+    let helpers_set: BTreeSet<_> = helpers.iter().cloned().collect();
+    for (helper, helper_deltas) in &deltas {
+        let zeta = compute_lagrange_coefficient(&helpers_set, Some(participant), *helper)?;
+        let sum = helper_deltas
+            .values()
+            .fold(<<C::Group as Group>::Field>::zero(), |acc, d| acc + *d);
+        let verifying_share =
+            pubkeys
+                .verifying_shares()
+                .get(helper)
+                .ok_or(Error::UnknownIdentifier {
+                    identifier: *helper,
+                })?;
+        if <C::Group>::generator() * sum != verifying_share.0 * zeta {
+            return Err(Error::InvalidSecretShare { culprit: *helper });
+        }
+    }
+
+    // Step 2: each helper sums the deltas it received.
+    let sigmas: Vec<Scalar<C>> = helpers
+        .iter()
+        .map(|helper| {
+            let received: Vec<Scalar<C>> = deltas
+                .values()
+                .map(|helper_deltas| helper_deltas[helper])
+                .collect();
+            repair_share_step_2::<C>(&received)
+        })
+        .collect();
+
+    // Step 3: the participant sums the sigmas to recover their share.
+    let repaired_share = repair_share_step_3(&sigmas, participant, &lost_share.commitment);
+    repaired_share.verify()?;
+    assert!(repaired_share.signing_share() == lost_share.signing_share());
+
+    // The repaired share can be used to sign with enough other participants.
+    shares.insert(participant, repaired_share);
+    let key_packages: BTreeMap<_, _> = std::iter::once(participant)
+        .chain(helpers.iter().cloned().take(min_signers as usize - 1))
+        .map(|identifier| {
+            let share = shares[&identifier].clone();
+            Ok((identifier, frost::keys::KeyPackage::try_from(share)?))
+        })
+        .collect::<Result<_, Error<C>>>()?;
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let message = "message to sign".as_bytes();
+    let signing_package = frost::SigningPackage::new(commitments_map, message);
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let signature_share =
+            frost::round2::sign(&signing_package, &nonces_map[identifier], key_package)?;
+        signature_shares.insert(*identifier, signature_share);
+    }
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys)?;
+    pubkeys.verifying_key().verify(message, &signature)?;
+
+    Ok(())
+}
+
+/// Simulates key generation with `max_signers` participants, the loss of the
+/// share of `lost_id` and its repair by `helper_ids`, checking that the
+/// repaired share is the original one and can be used to sign.
+pub fn simulate_repair<C: Ciphersuite, R: RngCore + CryptoRng>(
+    max_signers: u16,
+    min_signers: u16,
+    lost_id: u16,
+    helper_ids: &[u16],
+    rng: R,
+) -> Result<(), Error<C>> {
+    simulate_repair_with(max_signers, min_signers, lost_id, helper_ids, None, rng)
+}
+
+/// Like [`simulate_repair`], but the helper `malicious_id` corrupts one of its
+/// deltas; returns an [`Error::InvalidSecretShare`] naming the misbehaving
+/// helper.
+pub fn simulate_repair_with_malicious_helper<C: Ciphersuite, R: RngCore + CryptoRng>(
+    max_signers: u16,
+    min_signers: u16,
+    lost_id: u16,
+    helper_ids: &[u16],
+    malicious_id: u16,
+    rng: R,
+) -> Result<(), Error<C>> {
+    simulate_repair_with(
+        max_signers,
+        min_signers,
+        lost_id,
+        helper_ids,
+        Some(malicious_id),
+        rng,
+    )
+}
+
+/// Test the simulated repair of a share with honest helpers.
+pub fn check_simulate_repair<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    simulate_repair::<C, _>(5, 3, 2, &[1, 4, 5], &mut rng).unwrap();
+    simulate_repair::<C, _>(5, 3, 3, &[1, 2, 4], &mut rng).unwrap();
+    simulate_repair::<C, _>(7, 4, 7, &[1, 2, 3, 4, 5, 6], &mut rng).unwrap();
+}
+
+/// Test that a helper corrupting their deltas is detected.
+pub fn check_simulate_repair_with_malicious_helper<C: Ciphersuite, R: RngCore + CryptoRng>(
+    mut rng: R,
+) {
+    assert_eq!(
+        simulate_repair_with_malicious_helper::<C, _>(5, 3, 2, &[1, 4, 5], 4, &mut rng),
+        Err(Error::InvalidSecretShare {
+            culprit: Identifier::try_from(4).unwrap()
+        })
+    );
+}
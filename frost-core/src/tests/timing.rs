@@ -0,0 +1,171 @@
+//! Statistical timing tests for share verification, in the style of
+//! [dudect](https://eprint.iacr.org/2016/1123).
+//!
+//! Each test times an operation on inputs from two fixed classes (here, valid
+//! and invalid shares), picking the class of each measurement at random, and
+//! computes Welch's t-statistic of the two timing distributions. A large
+//! statistic means the timing depends on the class of the input.
+//!
+//! Timing measurements are noisy, so these tests can't prove constant-time
+//! behavior and only catch gross regressions; they use a generous
+//! [`T_THRESHOLD`]. They are slow and should be run in release mode on an
+//! otherwise idle machine, with the `timing-tests` feature of the ciphersuite
+//! crates, e.g.:
+//!
+//! ```sh
+//! cargo test --release -p frost-ristretto255 --features timing-tests --test timing_tests
+//! ```
+
+use std::{hint::black_box, time::Instant};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate as frost;
+use crate::{
+    challenge, compute_group_commitment_and_binding_factors, derive_interpolating_value,
+    Ciphersuite, Field, Group,
+};
+
+/// The number of measurements taken in each test, for both classes combined.
+pub const SAMPLES: usize = 20_000;
+
+/// The bound on the absolute value of Welch's t-statistic above which the
+/// tests fail. dudect considers values above 4.5 as evidence of a leak; a
+/// higher bound is used to avoid spurious failures on noisy machines.
+pub const T_THRESHOLD: f64 = 10.0;
+
+/// The fraction of the slowest measurements of each class that are discarded,
+/// since they are dominated by interruptions rather than by the operation.
+const CROP: f64 = 0.1;
+
+/// Times `f` on inputs of two classes, calling `f(true)` and `f(false)` in a
+/// random order, and returns Welch's t-statistic of the timings of the two
+/// classes.
+pub fn measure<R: RngCore + CryptoRng, F: FnMut(bool)>(
+    mut rng: R,
+    samples: usize,
+    mut f: F,
+) -> f64 {
+    let mut timings = [vec![], vec![]];
+    for _ in 0..samples {
+        let class = rng.next_u32() & 1 == 1;
+        let start = Instant::now();
+        f(class);
+        let elapsed = start.elapsed().as_nanos() as f64;
+        timings[class as usize].push(elapsed);
+    }
+
+    let [mut a, mut b] = timings;
+    crop(&mut a);
+    crop(&mut b);
+    welch_t(&a, &b)
+}
+
+/// Sorts the timings and discards the slowest [`CROP`] fraction of them.
+fn crop(timings: &mut Vec<f64>) {
+    timings.sort_by(|a, b| a.total_cmp(b));
+    timings.truncate(timings.len() - (timings.len() as f64 * CROP) as usize);
+}
+
+/// Computes Welch's t-statistic of two samples.
+fn welch_t(a: &[f64], b: &[f64]) -> f64 {
+    let mean_var = |x: &[f64]| {
+        let n = x.len() as f64;
+        let mean = x.iter().sum::<f64>() / n;
+        let var = x.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+        (mean, var, n)
+    };
+    let (mean_a, var_a, n_a) = mean_var(a);
+    let (mean_b, var_b, n_b) = mean_var(b);
+
+    (mean_a - mean_b) / (var_a / n_a + var_b / n_b).sqrt()
+}
+
+/// Test that verifying a valid signature share takes as long as verifying an
+/// invalid one.
+pub fn check_signature_share_verify_timing<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let mut shares = shares.into_iter();
+    let (identifier, secret_share) = shares.next().unwrap();
+    let (other_identifier, other_secret_share) = shares.next().unwrap();
+    let key_package = frost::keys::KeyPackage::try_from(secret_share).unwrap();
+
+    let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+    let (_, other_commitments) =
+        frost::round1::commit(other_secret_share.signing_share(), &mut rng);
+    let signing_package = frost::SigningPackage::new(
+        [
+            (identifier, commitments),
+            (other_identifier, other_commitments),
+        ]
+        .into(),
+        b"message to sign",
+    );
+    let valid_share = frost::round2::sign(&signing_package, &nonces, &key_package).unwrap();
+    let mut invalid_share = valid_share;
+    invalid_share.share = invalid_share.share + <<C::Group as Group>::Field>::one();
+
+    // Compute everything except the share verification itself beforehand, so
+    // that only the point comparison path is timed.
+    let (group_commitment, binding_factor_list) = compute_group_commitment_and_binding_factors(
+        &signing_package,
+        pubkeys.verifying_key(),
+        &[],
+    )
+    .unwrap();
+    let group_commitment_share =
+        commitments.to_group_commitment_share(binding_factor_list.get(&identifier).unwrap());
+    let lambda_i = derive_interpolating_value(&identifier, &signing_package).unwrap();
+    let challenge = challenge::<C>(
+        &group_commitment.0,
+        pubkeys.verifying_key(),
+        signing_package.message(),
+    );
+    let verifying_share = key_package.verifying_share();
+
+    let t = measure(&mut rng, SAMPLES, |valid| {
+        let share = if valid { &valid_share } else { &invalid_share };
+        let result = black_box(share).verify(
+            identifier,
+            &group_commitment_share,
+            verifying_share,
+            lambda_i,
+            &challenge,
+        );
+        assert_eq!(result.is_ok(), valid);
+    });
+    assert!(t.abs() < T_THRESHOLD, "t-statistic {t} exceeds threshold");
+}
+
+/// Test that verifying a valid secret share takes as long as verifying an
+/// invalid one.
+pub fn check_secret_share_verify_timing<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let (shares, _pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let valid_share = shares.into_values().next().unwrap();
+    let mut invalid_share = valid_share.clone();
+    invalid_share.signing_share.0 =
+        invalid_share.signing_share.0 + <<C::Group as Group>::Field>::one();
+
+    let t = measure(&mut rng, SAMPLES, |valid| {
+        let share = if valid { &valid_share } else { &invalid_share };
+        let result = black_box(share).verify();
+        assert_eq!(result.is_ok(), valid);
+    });
+    assert!(t.abs() < T_THRESHOLD, "t-statistic {t} exceeds threshold");
+}
@@ -0,0 +1,447 @@
+//! A toy ciphersuite over a tiny group, for testing only.
+//!
+//! [`ToyCiphersuite`] is a minimal example of how to implement [`Ciphersuite`]
+//! for a custom group. The group is the subgroup of prime order [`Q`] of the
+//! integers modulo the safe prime [`P`] `= 2 * Q + 1` under multiplication,
+//! written additively like the other ciphersuites: "adding" two elements
+//! multiplies them modulo [`P`], and "multiplying" an element by a scalar
+//! exponentiates it. The hash functions are 64-bit FNV-1a reduced modulo [`Q`].
+//!
+//! All values fit in a `u16`, so results can be checked by hand. For the same
+//! reason the toy ciphersuite provides no security whatsoever and MUST NOT be
+//! used outside of tests.
+
+use std::ops::{Add, Mul, Sub};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{Ciphersuite, Field, FieldError, Group, GroupError};
+
+/// The order of the toy group, and the modulus of its scalar field.
+pub const Q: u16 = 1019;
+
+/// The modulus of the integers the toy group elements are taken from.
+pub const P: u16 = 2 * Q + 1;
+
+/// The generator of the toy group, which has order [`Q`] since it is a square
+/// modulo [`P`] other than 1.
+pub const G: u16 = 4;
+
+/// Computes `base^exp mod modulus`.
+fn pow_mod(base: u16, exp: u16, modulus: u16) -> u16 {
+    let modulus = modulus as u32;
+    let mut base = base as u32 % modulus;
+    let mut exp = exp;
+    let mut result = 1u32;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u16
+}
+
+/// A scalar of the toy ciphersuite, i.e. an integer modulo [`Q`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToyScalar(u16);
+
+impl ToyScalar {
+    /// Creates a scalar from the given integer, reduced modulo [`Q`].
+    pub fn new(value: u16) -> Self {
+        Self(value % Q)
+    }
+
+    /// Returns the integer in `[0, Q)` representing the scalar.
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Add for ToyScalar {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(((self.0 as u32 + rhs.0 as u32) % Q as u32) as u16)
+    }
+}
+
+impl Sub for ToyScalar {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(((self.0 as u32 + Q as u32 - rhs.0 as u32) % Q as u32) as u16)
+    }
+}
+
+impl Mul for ToyScalar {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(((self.0 as u32 * rhs.0 as u32) % Q as u32) as u16)
+    }
+}
+
+/// An element of the toy group, i.e. an integer modulo [`P`] in the subgroup
+/// of order [`Q`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToyElement(u16);
+
+impl ToyElement {
+    /// Returns the integer in `[1, P)` representing the element.
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Add for ToyElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(((self.0 as u32 * rhs.0 as u32) % P as u32) as u16)
+    }
+}
+
+impl Sub for ToyElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        // The inverse of `rhs` modulo the prime P is rhs^(P-2)
+        self + Self(pow_mod(rhs.0, P - 2, P))
+    }
+}
+
+impl Mul<ToyScalar> for ToyElement {
+    type Output = Self;
+
+    fn mul(self, rhs: ToyScalar) -> Self {
+        Self(pow_mod(self.0, rhs.0, P))
+    }
+}
+
+/// The scalar field of the toy ciphersuite.
+#[derive(Clone, Copy)]
+pub struct ToyScalarField;
+
+impl Field for ToyScalarField {
+    type Scalar = ToyScalar;
+
+    type Serialization = [u8; 2];
+
+    fn zero() -> Self::Scalar {
+        ToyScalar(0)
+    }
+
+    fn one() -> Self::Scalar {
+        ToyScalar(1)
+    }
+
+    fn invert(scalar: &Self::Scalar) -> Result<Self::Scalar, FieldError> {
+        // Not constant-time, which is fine for a toy.
+        if *scalar == <Self as Field>::zero() {
+            Err(FieldError::InvalidZeroScalar)
+        } else {
+            // The inverse of `scalar` modulo the prime Q is scalar^(Q-2)
+            Ok(ToyScalar(pow_mod(scalar.0, Q - 2, Q)))
+        }
+    }
+
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self::Scalar {
+        ToyScalar((rng.next_u32() % Q as u32) as u16)
+    }
+
+    fn serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        scalar.0.to_le_bytes()
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Scalar, FieldError> {
+        let value = u16::from_le_bytes(*buf);
+        if value >= Q {
+            Err(FieldError::MalformedScalar)
+        } else {
+            Ok(ToyScalar(value))
+        }
+    }
+
+    fn little_endian_serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        Self::serialize(scalar)
+    }
+}
+
+/// The group of the toy ciphersuite.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ToyGroup;
+
+impl Group for ToyGroup {
+    type Field = ToyScalarField;
+
+    type Element = ToyElement;
+
+    type Serialization = [u8; 2];
+
+    fn cofactor() -> <Self::Field as Field>::Scalar {
+        ToyScalar(1)
+    }
+
+    fn identity() -> Self::Element {
+        ToyElement(1)
+    }
+
+    fn generator() -> Self::Element {
+        ToyElement(G)
+    }
+
+    fn serialize(element: &Self::Element) -> Self::Serialization {
+        element.0.to_le_bytes()
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Element, GroupError> {
+        let value = u16::from_le_bytes(*buf);
+        // Elements of the subgroup of order Q are exactly the x with x^Q = 1
+        if value == 0 || value >= P || pow_mod(value, Q, P) != 1 {
+            Err(GroupError::MalformedElement)
+        } else if value == 1 {
+            Err(GroupError::InvalidIdentityElement)
+        } else {
+            Ok(ToyElement(value))
+        }
+    }
+}
+
+fn hash_to_array(inputs: &[&[u8]]) -> [u8; 8] {
+    // 64-bit FNV-1a
+    let mut h: u64 = 0xcbf29ce484222325;
+    for i in inputs {
+        for b in i.iter() {
+            h ^= *b as u64;
+            h = h.wrapping_mul(0x100000001b3);
+        }
+    }
+    h.to_le_bytes()
+}
+
+fn hash_to_scalar(inputs: &[&[u8]]) -> ToyScalar {
+    let output = hash_to_array(inputs);
+    ToyScalar((u64::from_le_bytes(output) % Q as u64) as u16)
+}
+
+/// Context string of the toy ciphersuite.
+const CONTEXT_STRING: &str = "FROST-TOY-FNV1A-v1";
+
+/// The toy ciphersuite. See the [module documentation](self).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ToyCiphersuite;
+
+impl Ciphersuite for ToyCiphersuite {
+    const ID: &'static str = CONTEXT_STRING;
+
+    type Group = ToyGroup;
+
+    type HashOutput = [u8; 8];
+
+    type SignatureSerialization = [u8; 4];
+
+    fn H1(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"rho", m])
+    }
+
+    fn H2(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"chal", m])
+    }
+
+    fn H3(m: &[u8]) -> <<Self::Group as Group>::Field as Field>::Scalar {
+        hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"nonce", m])
+    }
+
+    fn H4(m: &[u8]) -> Self::HashOutput {
+        hash_to_array(&[CONTEXT_STRING.as_bytes(), b"msg", m])
+    }
+
+    fn H5(m: &[u8]) -> Self::HashOutput {
+        hash_to_array(&[CONTEXT_STRING.as_bytes(), b"com", m])
+    }
+
+    fn HDKG(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"dkg", m]))
+    }
+
+    fn HID(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"id", m]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use super::*;
+    use crate as frost;
+
+    type T = ToyCiphersuite;
+
+    #[test]
+    fn check_toy_group() {
+        // 4^5 = 1024, and 4^6 = 4096 = 2 * 2039 + 18
+        assert_eq!((ToyGroup::generator() * ToyScalar::new(5)).value(), 1024);
+        assert_eq!((ToyGroup::generator() * ToyScalar::new(6)).value(), 18);
+        // The generator has order Q
+        assert_eq!(
+            ToyGroup::generator() * ToyScalar::new(Q),
+            ToyGroup::identity()
+        );
+        // 2 * 510 = 1020 = 1 mod Q
+        assert_eq!(
+            ToyScalarField::invert(&ToyScalar::new(2)).unwrap(),
+            ToyScalar::new(510)
+        );
+        // -1 is not a square modulo P since P = 3 mod 4, thus not in the group
+        assert_eq!(
+            ToyGroup::deserialize(&(P - 1).to_le_bytes()),
+            Err(GroupError::MalformedElement)
+        );
+        assert_eq!(
+            ToyScalarField::deserialize(&Q.to_le_bytes()),
+            Err(FieldError::MalformedScalar)
+        );
+    }
+
+    /// The toy hash output is only 8 bytes long, shorter than the group id,
+    /// so the group id is padded with zeros; replay protection, which doesn't
+    /// depend on it, still works.
+    #[test]
+    fn check_toy_group_id() {
+        let mut rng = rand::thread_rng();
+        let signing_key = frost::SigningKey::<T>::new(&mut rng);
+        let (_shares, pubkeys) = frost::keys::split(
+            &signing_key,
+            3,
+            2,
+            frost::keys::IdentifierList::Default,
+            &mut rng,
+        )
+        .unwrap();
+
+        let group_id = pubkeys.group_id();
+        assert_eq!(group_id[8..], [0; 8]);
+        assert_ne!(group_id[..8], [0; 8]);
+
+        let message = b"toy";
+        let signature = signing_key.sign(&mut rng, message);
+        let mut cache = frost::replay::VerifierReplayCache::new();
+        cache.verify_once(&pubkeys, message, &signature).unwrap();
+        assert_eq!(
+            cache.verify_once(&pubkeys, message, &signature),
+            Err(frost::Error::ReplayDetected)
+        );
+    }
+
+    /// Runs a 2-of-3 signing round with signers 1 and 3, checking the
+    /// intermediate values by hand.
+    #[test]
+    fn check_toy_sign_2_of_3() {
+        let s = |v: u16| ToyScalar::new(v);
+
+        // The secret polynomial is f(x) = 7 + 3x, so the shares are
+        // f(1) = 10, f(2) = 13 and f(3) = 16.
+        let identifiers = frost::keys::default_identifiers::<T>(3);
+        let secret_shares = frost::keys::generate_secret_shares(
+            &frost::SigningKey { scalar: s(7) },
+            3,
+            2,
+            vec![s(3)],
+            &identifiers,
+        )
+        .unwrap();
+        let shares: Vec<_> = secret_shares
+            .iter()
+            .map(|share| share.signing_share.0.value())
+            .collect();
+        assert_eq!(shares, vec![10, 13, 16]);
+
+        // The verifying key is 4^7 = 16384 = 8 * 2039 + 72
+        let pubkeys = frost::keys::PublicKeyPackage::from_commitment(
+            &identifiers.iter().copied().collect(),
+            &secret_shares[0].commitment,
+        )
+        .unwrap();
+        assert_eq!(pubkeys.verifying_key().element.value(), 72);
+
+        let key_packages: BTreeMap<_, _> = secret_shares
+            .into_iter()
+            .filter(|share| share.identifier != identifiers[1])
+            .map(|share| {
+                (
+                    share.identifier,
+                    frost::keys::KeyPackage::try_from(share).unwrap(),
+                )
+            })
+            .collect();
+
+        // The Lagrange coefficients for signers {1, 3} at 0 are
+        // 3 / (3 - 1) = 3 * 510 = 511 and 1 / (1 - 3) = -510 = 509,
+        // and 511 * 10 + 509 * 16 = 13254 = 13 * 1019 + 7.
+        let signers: BTreeSet<_> = key_packages.keys().copied().collect();
+        let lambdas: Vec<_> = signers
+            .iter()
+            .map(|id| {
+                frost::compute_lagrange_coefficient(&signers, None, *id)
+                    .unwrap()
+                    .value()
+            })
+            .collect();
+        assert_eq!(lambdas, vec![511, 509]);
+
+        // Fixed nonces (d, e): (5, 6) for signer 1 and (8, 9) for signer 3,
+        // with hiding commitments 4^5 = 1024 and 4^8 = 65536 = 32 * 2039 + 288
+        let nonces: BTreeMap<_, _> = signers
+            .iter()
+            .zip([(5, 6), (8, 9)])
+            .map(|(id, (d, e))| {
+                (
+                    *id,
+                    frost::round1::SigningNonces::from_nonces(
+                        frost::round1::Nonce(s(d)),
+                        frost::round1::Nonce(s(e)),
+                    ),
+                )
+            })
+            .collect();
+        let hiding_commitments: Vec<_> = nonces
+            .values()
+            .map(|n| n.commitments.hiding.0.value())
+            .collect();
+        assert_eq!(hiding_commitments, vec![1024, 288]);
+
+        let commitments = nonces.iter().map(|(id, n)| (*id, n.commitments)).collect();
+        let message = b"toy";
+        let signing_package = frost::SigningPackage::new(commitments, message);
+
+        let signature_shares: BTreeMap<_, _> = key_packages
+            .iter()
+            .map(|(id, key_package)| {
+                (
+                    *id,
+                    frost::round2::sign(&signing_package, &nonces[id], key_package).unwrap(),
+                )
+            })
+            .collect();
+        let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+        pubkeys.verifying_key().verify(message, &signature).unwrap();
+
+        // Recompute the signature by hand from the binding factors rho_i and
+        // the challenge c: R = 4^k and z = k + c * 7 where
+        // k = (5 + 6 * rho_1) + (8 + 9 * rho_3).
+        let binding_factor_list =
+            frost::compute_binding_factor_list(&signing_package, pubkeys.verifying_key(), &[]);
+        let rho: Vec<_> = signers
+            .iter()
+            .map(|id| binding_factor_list.get(id).unwrap().0)
+            .collect();
+        let k = (s(5) + s(6) * rho[0]) + (s(8) + s(9) * rho[1]);
+        let R = ToyGroup::generator() * k;
+        let c = frost::challenge::<T>(&R, pubkeys.verifying_key(), message).0;
+        assert_eq!(signature.R, R);
+        assert_eq!(signature.z, k + c * s(7));
+    }
+}
@@ -0,0 +1,178 @@
+//! Reproducible transcripts of a whole signing operation, for bug reports.
+//!
+//! [`transcript_from_seed`] runs key generation with a trusted dealer, both
+//! signing rounds and aggregation with an RNG seeded from the given seed, so
+//! that the same seed always produces the same transcript. The transcript
+//! includes every secret, so it must only be generated for test keys.
+//! Maintainers can check a transcript attached to a bug report with
+//! [`SigningTranscript::replay`].
+
+use std::collections::BTreeMap;
+
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+
+use crate as frost;
+use crate::{
+    keys::{KeyPackage, PublicKeyPackage, SecretShare},
+    round1::SigningNonces,
+    round2::SignatureShare,
+    serialization::{Deserialize, Serialize},
+    Ciphersuite, Error, Identifier, Signature, SigningPackage,
+};
+
+/// Every message and intermediate value of a signing operation run by
+/// [`transcript_from_seed`].
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+pub struct SigningTranscript<C: Ciphersuite> {
+    /// The seed of the RNG used for the whole operation.
+    pub seed: [u8; 32],
+    /// The secret shares dealt to all participants.
+    pub secret_shares: BTreeMap<Identifier<C>, SecretShare<C>>,
+    /// The public key package of the group.
+    pub public_key_package: PublicKeyPackage<C>,
+    /// The nonces generated by each signer in round 1.
+    pub signing_nonces: BTreeMap<Identifier<C>, SigningNonces<C>>,
+    /// The signing package assembled by the coordinator.
+    pub signing_package: SigningPackage<C>,
+    /// The signature shares generated by each signer in round 2.
+    pub signature_shares: BTreeMap<Identifier<C>, SignatureShare<C>>,
+    /// The aggregated signature.
+    pub signature: Signature<C>,
+}
+
+impl<C> SigningTranscript<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+
+    /// Recomputes the signature shares and the signature from the recorded
+    /// secret shares, nonces and signing package, checking that they match
+    /// the recorded ones and that the signature is valid.
+    pub fn replay(&self) -> Result<(), Error<C>> {
+        let mut signature_shares = BTreeMap::new();
+        for (identifier, nonces) in &self.signing_nonces {
+            let secret_share =
+                self.secret_shares
+                    .get(identifier)
+                    .ok_or(Error::UnknownIdentifier {
+                        identifier: *identifier,
+                    })?;
+            let key_package = KeyPackage::try_from(secret_share.clone())?;
+            let signature_share = frost::round2::sign(&self.signing_package, nonces, &key_package)?;
+            if self.signature_shares.get(identifier) != Some(&signature_share) {
+                return Err(Error::InvalidSignatureShare {
+                    culprit: *identifier,
+                });
+            }
+            signature_shares.insert(*identifier, signature_share);
+        }
+
+        let signature = frost::aggregate(
+            &self.signing_package,
+            &signature_shares,
+            &self.public_key_package,
+        )?;
+        if signature != self.signature {
+            return Err(Error::InvalidSignature);
+        }
+
+        self.public_key_package
+            .verifying_key()
+            .verify(self.signing_package.message(), &signature)
+    }
+}
+
+/// Deterministically runs a signing operation of `message` by the first
+/// `min_signers` of `max_signers` participants, with an RNG seeded from
+/// `seed`, and records it.
+pub fn transcript_from_seed<C: Ciphersuite>(
+    seed: [u8; 32],
+    max_signers: u16,
+    min_signers: u16,
+    message: &[u8],
+) -> Result<SigningTranscript<C>, Error<C>> {
+    let mut rng = ChaCha20Rng::from_seed(seed);
+
+    let (secret_shares, public_key_package) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )?;
+
+    let mut key_packages = BTreeMap::new();
+    let mut signing_nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (identifier, secret_share) in secret_shares.iter().take(min_signers as usize) {
+        let key_package = KeyPackage::try_from(secret_share.clone())?;
+        let (nonces, signer_commitments) =
+            frost::round1::commit(key_package.signing_share(), &mut rng);
+        key_packages.insert(*identifier, key_package);
+        signing_nonces.insert(*identifier, nonces);
+        commitments.insert(*identifier, signer_commitments);
+    }
+    let signing_package = SigningPackage::new(commitments, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, nonces) in &signing_nonces {
+        let signature_share =
+            frost::round2::sign(&signing_package, nonces, &key_packages[identifier])?;
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let signature = frost::aggregate(&signing_package, &signature_shares, &public_key_package)?;
+
+    Ok(SigningTranscript {
+        seed,
+        secret_shares,
+        public_key_package,
+        signing_nonces,
+        signing_package,
+        signature_shares,
+        signature,
+    })
+}
+
+/// Test that transcripts generated from the same seed are identical and can
+/// be replayed, and that a tampered transcript is detected.
+pub fn check_transcript_from_seed<C: Ciphersuite>() {
+    let seed = [42; 32];
+    let message = b"message to sign";
+
+    let transcript = transcript_from_seed::<C>(seed, 5, 3, message).unwrap();
+    let bytes = transcript.serialize().unwrap();
+    let again = transcript_from_seed::<C>(seed, 5, 3, message).unwrap();
+    assert_eq!(again.serialize().unwrap(), bytes);
+
+    let other = transcript_from_seed::<C>([43; 32], 5, 3, message).unwrap();
+    assert_ne!(other.serialize().unwrap(), bytes);
+
+    let restored = SigningTranscript::<C>::deserialize(&bytes).unwrap();
+    restored.replay().unwrap();
+
+    // A transcript whose signature share was swapped fails to replay.
+    let mut tampered = restored.clone();
+    let mut shares = tampered.signature_shares.values().copied();
+    let (first, second) = (shares.next().unwrap(), shares.next().unwrap());
+    let identifier = *tampered.signature_shares.keys().next().unwrap();
+    assert_ne!(first, second);
+    tampered.signature_shares.insert(identifier, second);
+    assert_eq!(
+        tampered.replay(),
+        Err(Error::InvalidSignatureShare {
+            culprit: identifier
+        })
+    );
+}
@@ -268,13 +268,28 @@ pub fn check_sign_with_test_vectors<C: Ciphersuite>(json_vectors: &Value) {
         assert_eq!(*input, binding_factor_inputs[identifier]);
     }
 
-    let binding_factor_list: frost::BindingFactorList<C> =
-        compute_binding_factor_list(&signing_package, &verifying_key, &[]);
+    let (group_commitment, binding_factor_list) =
+        compute_group_commitment_and_binding_factors(&signing_package, &verifying_key, &[])
+            .unwrap();
 
     for (identifier, binding_factor) in binding_factor_list.0.iter() {
         assert_eq!(*binding_factor, binding_factors[identifier]);
     }
 
+    // The vectors don't list the group commitment and challenge, but they are
+    // determined by the signature: R is the group commitment, and z must
+    // satisfy z * G = R + c * PK for the challenge c.
+    let vector_signature =
+        Signature::<C>::deserialize(signature_bytes.clone().try_into().debugless_unwrap()).unwrap();
+
+    assert!(group_commitment.0 == vector_signature.R);
+
+    let challenge = challenge::<C>(&group_commitment.0, &verifying_key, &message_bytes);
+    assert!(
+        <C::Group>::generator() * vector_signature.z
+            == vector_signature.R + verifying_key.element * challenge.0
+    );
+
     let mut our_signature_shares = BTreeMap::new();
 
     // Each participant generates their signature share
@@ -11,8 +11,8 @@ use debugless_unwrap::DebuglessUnwrap;
 use rand_core::{CryptoRng, RngCore};
 use serde_json::Value;
 
-use crate::keys::{generate_with_dealer, IdentifierList, PublicKeyPackage};
-use crate::Ciphersuite;
+use crate::keys::{generate_with_dealer, verify_shares, IdentifierList, PublicKeyPackage};
+use crate::{Ciphersuite, Error, Field};
 
 /// Test serialize VerifiableSecretSharingCommitment
 pub fn check_serialize_vss_commitment<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
@@ -125,3 +125,57 @@ pub fn check_compute_public_key_package<C: Ciphersuite, R: RngCore + CryptoRng>(
         PublicKeyPackage::from_commitment(&members, &group_commitment).unwrap()
     );
 }
+
+/// Test verifying many secret shares at once.
+pub fn check_verify_shares<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (secret_shares, _) =
+        generate_with_dealer::<C, _>(max_signers, min_signers, IdentifierList::Default, &mut rng)
+            .unwrap();
+    let mut secret_shares: Vec<_> = secret_shares.into_values().collect();
+
+    // A consistent set of shares verifies
+    verify_shares(&secret_shares, &mut rng).unwrap();
+
+    // A tampered share is reported
+    let tampered = &mut secret_shares[2];
+    tampered.signing_share.0 = tampered.signing_share.0 + <<C::Group as Group>::Field>::one();
+    let culprit = tampered.identifier;
+    assert_eq!(
+        verify_shares(&secret_shares, &mut rng),
+        Err(Error::InvalidSecretShare { culprit })
+    );
+}
+
+/// Test checking the group verifying key committed to by a commitment.
+pub fn check_vss_commitment_verifying_key<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 3;
+    let min_signers = 2;
+    let (secret_shares, public_key_package) =
+        generate_with_dealer::<C, _>(max_signers, min_signers, IdentifierList::Default, &mut rng)
+            .unwrap();
+    let commitment = secret_shares.values().next().unwrap().commitment().clone();
+
+    assert_eq!(
+        commitment.verifying_key().unwrap(),
+        *public_key_package.verifying_key()
+    );
+    commitment
+        .check_verifying_key(public_key_package.verifying_key())
+        .unwrap();
+
+    let (_, other_public_key_package) =
+        generate_with_dealer::<C, _>(max_signers, min_signers, IdentifierList::Default, &mut rng)
+            .unwrap();
+    assert_eq!(
+        commitment.check_verifying_key(other_public_key_package.verifying_key()),
+        Err(Error::MismatchedGroupPublicKey)
+    );
+
+    let empty = VerifiableSecretSharingCommitment::<C>::new(vec![]);
+    assert_eq!(
+        empty.check_verifying_key(public_key_package.verifying_key()),
+        Err(Error::MissingCommitment)
+    );
+}
@@ -40,6 +40,14 @@ pub trait Field: Copy + Clone {
     /// element is zero.
     fn invert(scalar: &Self::Scalar) -> Result<Self::Scalar, FieldError>;
 
+    /// Computes the additive inverse of an element of the scalar field.
+    ///
+    /// The default implementation subtracts the scalar from zero, which is
+    /// constant-time if the subtraction of [`Field::Scalar`] is.
+    fn negate(scalar: &Self::Scalar) -> Self::Scalar {
+        Self::zero() - *scalar
+    }
+
     /// Generate a random scalar from the entire space [0, l-1]
     ///
     /// <https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-3.1-3.3>
@@ -113,6 +121,14 @@ pub trait Group: Copy + Clone + PartialEq {
     /// [`ScalarBaseMult()`]: https://www.ietf.org/archive/id/draft-irtf-cfrg-frost-14.html#section-3.1-3.5
     fn generator() -> Self::Element;
 
+    /// Computes the additive inverse of an element of the group.
+    ///
+    /// The default implementation subtracts the element from the identity,
+    /// which is constant-time if the subtraction of [`Group::Element`] is.
+    fn negate(element: &Self::Element) -> Self::Element {
+        Self::identity() - *element
+    }
+
     /// A member function of a group _G_ that maps an [`Element`] to a unique byte array buf of
     /// fixed length Ne.
     ///
@@ -8,6 +8,20 @@ use crate::{Challenge, Ciphersuite, Element, Error, Group, Signature};
 #[cfg(feature = "serde")]
 use crate::serialization::ElementSerialization;
 
+/// The encoding of a signature passed to [`VerifyingKey::verify_encoded`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SignatureFormat {
+    /// The raw encoding of the ciphersuite, i.e. the encoded commitment `R`
+    /// followed by the encoded response `z`, as returned by
+    /// [`Signature::serialize`]. This is e.g. the 64-byte form for Ed25519
+    /// and ristretto255.
+    Raw,
+    /// The DER encoding, if the ciphersuite defines one, and the raw encoding
+    /// otherwise. Currently no ciphersuite defines a DER encoding.
+    DerIfApplicable,
+}
+
 /// A valid verifying key for Schnorr signatures over a FROST [`Ciphersuite::Group`].
 #[derive(Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -79,6 +93,59 @@ where
         C::verify_signature(msg, signature, self)
     }
 
+    /// Verify a purported `signature` over `msg` bound to `network_id`, made
+    /// with a [`crate::SigningPackage`] created with
+    /// [`crate::SigningPackage::new_with_network_id`].
+    ///
+    /// The network id is part of the challenge, so signatures bound to a
+    /// different network id, or not bound to any, are rejected. An empty
+    /// `network_id` verifies signatures that aren't bound to any network, like
+    /// [`VerifyingKey::verify`].
+    pub fn verify_with_network_id(
+        &self,
+        network_id: &[u8],
+        msg: &[u8],
+        signature: &Signature<C>,
+    ) -> Result<(), Error<C>> {
+        if network_id.is_empty() {
+            return self.verify(msg, signature);
+        }
+
+        let challenge = crate::challenge_with_network_id::<C>(&signature.R, self, network_id, msg);
+
+        self.verify_prehashed(challenge, signature)
+    }
+
+    /// Verify a purported signature over `msg` made by this verification key,
+    /// given encoded in the specified [`SignatureFormat`].
+    ///
+    /// Returns [`Error::MalformedSignature`] if `signature` can't be parsed in
+    /// that format, e.g. if it is truncated or has trailing bytes.
+    pub fn verify_encoded(
+        &self,
+        msg: &[u8],
+        signature: &[u8],
+        format: SignatureFormat,
+    ) -> Result<(), Error<C>> {
+        let signature = match format {
+            // None of the ciphersuites define a DER encoding for Schnorr
+            // signatures, so the raw encoding is used for both.
+            SignatureFormat::Raw | SignatureFormat::DerIfApplicable => {
+                if signature.len() != Signature::<C>::encoded_len() {
+                    return Err(Error::MalformedSignature);
+                }
+                Signature::<C>::deserialize(
+                    signature
+                        .to_vec()
+                        .try_into()
+                        .map_err(|_| Error::MalformedSignature)?,
+                )?
+            }
+        };
+
+        self.verify(msg, &signature)
+    }
+
     /// Computes the group public key given the group commitment.
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     pub(crate) fn from_commitment(
@@ -15,5 +15,37 @@ fn bench_ed25519_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Ed25519Sha512, _>(c, "ed25519", &mut rng);
 }
 
-criterion_group!(benches, bench_ed25519_batch_verify, bench_ed25519_sign);
+fn bench_ed25519_group_commitment(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_group_commitment::<Ed25519Sha512, _>(c, "ed25519", &mut rng);
+}
+
+fn bench_ed25519_verify_signature_shares_batch(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_verify_signature_shares_batch::<Ed25519Sha512, _>(
+        c, "ed25519", &mut rng,
+    );
+}
+
+fn bench_ed25519_lambda_cache(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_lambda_cache::<Ed25519Sha512, _>(c, "ed25519", &mut rng);
+}
+
+fn bench_ed25519_signing_set_sizes(c: &mut Criterion) {
+    frost_core::benches::bench_signing_set_sizes::<Ed25519Sha512>(c, "ed25519");
+}
+
+criterion_group!(
+    benches,
+    bench_ed25519_batch_verify,
+    bench_ed25519_sign,
+    bench_ed25519_group_commitment,
+    bench_ed25519_verify_signature_shares_batch,
+    bench_ed25519_lambda_cache,
+    bench_ed25519_signing_set_sizes
+);
 criterion_main!(benches);
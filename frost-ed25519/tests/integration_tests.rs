@@ -8,6 +8,21 @@ fn check_zero_key_fails() {
     frost_core::tests::ciphersuite_generic::check_zero_key_fails::<Ed25519Sha512>();
 }
 
+#[test]
+fn check_zero_identifier_fails() {
+    frost_core::tests::ciphersuite_generic::check_zero_identifier_fails::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_keygen_from_coefficients() {
+    frost_core::tests::ciphersuite_generic::check_keygen_from_coefficients::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_identifier_display() {
+    frost_core::tests::ciphersuite_generic::check_identifier_display::<Ed25519Sha512>();
+}
+
 #[test]
 fn check_sign_with_dkg() {
     let rng = thread_rng();
@@ -15,6 +30,22 @@ fn check_sign_with_dkg() {
     frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<Ed25519Sha512, _>(rng);
 }
 
+#[test]
+fn check_dkg_secret_packages_zeroize() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_dkg_secret_packages_zeroize::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_key_packages_zeroize() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_packages_zeroize::<Ed25519Sha512, _>(rng);
+}
+
 #[test]
 fn check_dkg_part1_fails_with_invalid_signers_min_signers() {
     let rng = thread_rng();
@@ -64,6 +95,22 @@ fn check_rts() {
     frost_core::tests::repairable::check_rts::<Ed25519Sha512, _>(rng);
 }
 
+#[test]
+fn check_simulate_repair() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_simulate_repair::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_simulate_repair_with_malicious_helper() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_simulate_repair_with_malicious_helper::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
 #[test]
 fn check_sign_with_dealer() {
     let rng = thread_rng();
@@ -71,6 +118,167 @@ fn check_sign_with_dealer() {
     frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<Ed25519Sha512, _>(rng);
 }
 
+#[test]
+fn check_secret_share_coefficient_count() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_secret_share_coefficient_count::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_group_commitment_and_binding_factors() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_commitment_and_binding_factors::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregate_from_bytes() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_from_bytes::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_group_commitment_matches_signature() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_commitment_matches_signature::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signature_from_components() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_from_components::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_encoded_lengths() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_encoded_lengths::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_combiner() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_combiner::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_streaming() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_streaming::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_verify_signature_shares_batch() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_signature_shares_batch::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signing_report() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_report::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_mismatched_group_public_key() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_mismatched_group_public_key::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_signature_share_with_lambda() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_signature_share_with_lambda::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_provider() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_provider::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_aux_rand() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_aux_rand::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_after_recommit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_after_recommit::<Ed25519Sha512, _>(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_sign_with_serialized_nonces() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_serialized_nonces::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_key_package_try_from_bytes() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_try_from_bytes::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_serialization_independent_of_insertion_order() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_serialization_independent_of_insertion_order::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_select_committee() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_select_committee::<Ed25519Sha512, _>(rng);
+}
+
 #[test]
 fn check_sign_with_dealer_fails_with_invalid_min_signers() {
     let rng = thread_rng();
@@ -202,6 +410,21 @@ fn check_identifier_derivation() {
     frost_core::tests::ciphersuite_generic::check_identifier_derivation::<Ed25519Sha512>();
 }
 
+#[test]
+fn check_identifiers_unique() {
+    frost_core::tests::ciphersuite_generic::check_identifiers_unique::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_identifier_derivation_from_verifying_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_identifier_derivation_from_verifying_share::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
 // Explicit test which is used in a documentation snippet
 #[test]
 #[allow(unused_variables)]
@@ -238,3 +461,231 @@ fn check_sign_with_incorrect_commitments() {
         rng,
     );
 }
+
+#[test]
+fn check_sign_dry_run() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_dry_run::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_split() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_split::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_custodial() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_custodial::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_threshold_decryption() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_threshold_decryption::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_cached() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_cached::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_key_package_against_public_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_against_public_package::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_key_package_verify_against_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_verify_against_commitment::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_verifying_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_verifying_share::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_verification_tuples() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_verification_tuples::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_group_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_group_id::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_prove_possession() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_prove_possession::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_designated() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_designated::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_share_generation_with_derived_identifiers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_share_generation_with_derived_identifiers::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_encoded() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_encoded::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_network_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_network_id::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signing_package_new_checked() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_new_checked::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_with_identical_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_identical_commitments::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signing_package_against_public_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_against_public_package::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_reconstruct_secret() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_reconstruct_secret::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signing_package_builder() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_builder::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_refresh_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_refresh_shares::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_signing_package_num_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_num_commitments::<Ed25519Sha512, _>(
+        rng,
+    );
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_transcript_from_seed() {
+    frost_core::tests::transcript::check_transcript_from_seed::<Ed25519Sha512>();
+}
+
+#[test]
+fn check_negate() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_negate::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_verifier_replay_cache() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verifier_replay_cache::<Ed25519Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_with_commitment_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_commitment_shares::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregate_with_signing_package_digests() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_signing_package_digests::<
+        Ed25519Sha512,
+        _,
+    >(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_codecs() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_codecs::<Ed25519Sha512, _>(rng);
+}
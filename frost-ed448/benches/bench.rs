@@ -17,5 +17,36 @@ fn bench_ed448_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Ed448Shake256, _>(c, "ed448", &mut rng);
 }
 
-criterion_group!(benches, bench_ed448_sign);
+fn bench_ed448_group_commitment(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_group_commitment::<Ed448Shake256, _>(c, "ed448", &mut rng);
+}
+
+fn bench_ed448_verify_signature_shares_batch(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_verify_signature_shares_batch::<Ed448Shake256, _>(
+        c, "ed448", &mut rng,
+    );
+}
+
+fn bench_ed448_lambda_cache(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_lambda_cache::<Ed448Shake256, _>(c, "ed448", &mut rng);
+}
+
+fn bench_ed448_signing_set_sizes(c: &mut Criterion) {
+    frost_core::benches::bench_signing_set_sizes::<Ed448Shake256>(c, "ed448");
+}
+
+criterion_group!(
+    benches,
+    bench_ed448_sign,
+    bench_ed448_group_commitment,
+    bench_ed448_verify_signature_shares_batch,
+    bench_ed448_lambda_cache,
+    bench_ed448_signing_set_sizes
+);
 criterion_main!(benches);
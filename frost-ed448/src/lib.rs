@@ -24,7 +24,7 @@ use frost_core as frost;
 mod tests;
 
 // Re-exports in our public API
-pub use frost_core::{serde, Ciphersuite, Field, FieldError, Group, GroupError};
+pub use frost_core::{serde, Ciphersuite, Field, FieldError, Group, GroupError, SignatureFormat};
 pub use rand_core;
 
 /// An error.
@@ -255,6 +255,17 @@ pub mod keys {
         frost::keys::split(secret, max_signers, min_signers, identifiers, rng)
     }
 
+    /// Generates FROST shares from a fully specified secret polynomial, without
+    /// using any randomness.
+    ///
+    /// `coefficients[0]` is the secret and the threshold is `coefficients.len()`.
+    pub fn keygen_from_coefficients(
+        coefficients: &[frost::Scalar<E>],
+        identifiers: &[Identifier],
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::keygen_from_coefficients(coefficients, identifiers)
+    }
+
     /// Recompute the secret from t-of-n secret shares using Lagrange interpolation.
     ///
     /// This can be used if for some reason the original key must be restored; e.g.
@@ -270,6 +281,48 @@ pub mod keys {
         frost::keys::reconstruct(secret_shares)
     }
 
+    /// Recompute the secret from at least `min_signers` [`SecretShare`]s using
+    /// Lagrange interpolation, checking that the shares are consistent.
+    ///
+    /// The threshold is inferred from the shares' commitment, so providing
+    /// less than `min_signers` shares returns an error.
+    pub fn reconstruct_secret(secret_shares: &[SecretShare]) -> Result<SigningKey, Error> {
+        frost::keys::reconstruct_secret(secret_shares)
+    }
+
+    /// Refreshes the shares of all the participants of a group without
+    /// changing its verifying key, by adding a fresh sharing of zero to them.
+    ///
+    /// Shares that are not refreshed can't be used with the refreshed ones.
+    pub fn refresh_shares<RNG: RngCore + CryptoRng>(
+        secret_shares: &[SecretShare],
+        mut rng: RNG,
+    ) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+        frost::keys::refresh_shares(secret_shares, &mut rng)
+    }
+
+    /// Produces a signature with key packages that are all held by the caller,
+    /// running both rounds for each of them with fresh nonces and aggregating
+    /// the signature shares.
+    pub fn sign_custodial<RNG: RngCore + CryptoRng>(
+        key_packages: &[KeyPackage],
+        message: &[u8],
+        rng: RNG,
+    ) -> Result<Signature, Error> {
+        frost::keys::sign_custodial(key_packages, message, rng)
+    }
+
+    /// Verifies many [`SecretShare`]s generated from the same commitment at once.
+    ///
+    /// This is faster than calling [`SecretShare::verify`] for each share. If
+    /// verification fails, the first invalid share is reported.
+    pub fn verify_shares<RNG: RngCore + CryptoRng>(
+        secret_shares: &[SecretShare],
+        rng: RNG,
+    ) -> Result<(), Error> {
+        frost::keys::verify_shares(secret_shares, rng)
+    }
+
     /// Secret and public key material generated by a dealer performing
     /// [`generate_with_dealer`].
     ///
@@ -299,6 +352,10 @@ pub mod keys {
     /// Used for verification purposes before publishing a signature.
     pub type PublicKeyPackage = frost::keys::PublicKeyPackage<E>;
 
+    /// A proof that a FROST(Ed448, SHAKE256) participant holds the signing share matching
+    /// their verifying share.
+    pub type PossessionProof = frost::keys::PossessionProof<E>;
+
     /// Contains the commitments to the coefficients for our secret polynomial _f_,
     /// used to generate participants' key shares.
     ///
@@ -330,6 +387,13 @@ pub mod round1 {
     /// signing key.
     pub type SigningNonces = frost::round1::SigningNonces<E>;
 
+    /// A FROST(Ed448, SHAKE256) hiding or binding nonce.
+    pub type Nonce = frost::round1::Nonce<E>;
+
+    /// The hiding and binding nonces of a [`SigningNonces`], split so that
+    /// they can be stored in separate locations.
+    pub type SplitNonces = frost::round1::SplitNonces<E>;
+
     /// Published by each participant in the first round of the signing protocol.
     ///
     /// This step can be batched if desired by the implementation. Each
@@ -339,6 +403,10 @@ pub mod round1 {
     /// A commitment to a signing nonce share.
     pub type NonceCommitment = frost::round1::NonceCommitment<E>;
 
+    /// A participant's share of the group commitment, derived from their
+    /// commitments and their binding factor.
+    pub type GroupCommitmentShare = frost::round1::GroupCommitmentShare<E>;
+
     /// Performed once by each participant selected for the signing operation.
     ///
     /// Generates the signing nonces and commitments to be used in the signing
@@ -349,12 +417,46 @@ pub mod round1 {
     {
         frost::round1::commit::<E, RNG>(secret, rng)
     }
+
+    /// Like [`commit`], but also hashes the given auxiliary randomness into the
+    /// signing nonces, to further hedge against a biased RNG.
+    pub fn commit_with_aux_rand<RNG>(
+        secret: &SigningShare,
+        aux_rand: &[u8; 32],
+        rng: &mut RNG,
+    ) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit_with_aux_rand::<E, RNG>(secret, aux_rand, rng)
+    }
+
+    /// Restarts round 1 for a participant, consuming and zeroizing their old
+    /// nonces and generating fresh nonces and commitments like [`commit`].
+    pub fn recommit<RNG>(
+        old_nonces: SigningNonces,
+        secret: &SigningShare,
+        rng: &mut RNG,
+    ) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::recommit::<E, RNG>(old_nonces, secret, rng)
+    }
+
+    /// The FROST(Ed448, SHAKE256) signing commitments invalidated by restarting round 1,
+    /// kept by the coordinator.
+    pub type StaleCommitments = frost::round1::StaleCommitments<E>;
 }
 
 /// Generated by the coordinator of the signing operation and distributed to
 /// each signing party.
 pub type SigningPackage = frost::SigningPackage<E>;
 
+/// Accumulates the participants' commitments into a [`SigningPackage`] as
+/// they are received, in any order.
+pub type SigningPackageBuilder = frost::SigningPackageBuilder<E>;
+
 /// FROST(Ed448, SHAKE256) Round 2 functionality and types, for signature share generation.
 pub mod round2 {
     use super::*;
@@ -378,11 +480,169 @@ pub mod round2 {
     ) -> Result<SignatureShare, Error> {
         frost::round2::sign(signing_package, signer_nonces, key_package)
     }
+
+    /// Like [`sign`], but with the hiding and binding nonces supplied
+    /// separately, e.g. from a [`round1::SplitNonces`].
+    pub fn sign_split(
+        signing_package: &SigningPackage,
+        hiding_nonce: &round1::Nonce,
+        binding_nonce: &round1::Nonce,
+        commitments: &round1::SigningCommitments,
+        key_package: &keys::KeyPackage,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_split(
+            signing_package,
+            hiding_nonce,
+            binding_nonce,
+            commitments,
+            key_package,
+        )
+    }
+
+    /// Computes the participant's share of the group commitment from the
+    /// nonces they sign with, to be sent to a combiner using
+    /// [`aggregate_with_commitment_shares`](crate::aggregate_with_commitment_shares).
+    pub fn group_commitment_share(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+    ) -> Result<round1::GroupCommitmentShare, Error> {
+        frost::round2::group_commitment_share(signing_package, signer_nonces, key_package)
+    }
+
+    /// Runs every validation and computation of [`sign`] but discards the
+    /// resulting signature share, returning only whether signing would succeed.
+    pub fn sign_dry_run(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+    ) -> Result<(), Error> {
+        frost::round2::sign_dry_run(signing_package, signer_nonces, key_package)
+    }
+
+    /// A cache of FROST(Ed448, SHAKE256) Lagrange coefficients for a fixed set of signers.
+    pub type LambdaCache = frost::round2::LambdaCache<E>;
+
+    /// Like [`sign`], but looks up the Lagrange coefficient of the signer in the
+    /// given [`LambdaCache`], computing and storing it if missing.
+    pub fn sign_cached(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        cache: &mut LambdaCache,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_cached(signing_package, signer_nonces, key_package, cache)
+    }
+}
+
+/// FROST(Ed448, SHAKE256) designated-verifier signatures, which only convince the
+/// verifier that chose the challenge nonce.
+pub mod designated {
+    use super::*;
+
+    /// A random nonce chosen by the FROST(Ed448, SHAKE256) designated verifier.
+    pub type VerifierNonce = frost::designated::VerifierNonce<E>;
+
+    /// The commitment to a [`VerifierNonce`], published by the designated verifier.
+    pub type VerifierCommitment = frost::designated::VerifierCommitment<E>;
+
+    /// Performed once by each participant selected for the signing operation,
+    /// after the designated verifier revealed their nonce.
+    pub fn sign(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        verifier_commitment: &VerifierCommitment,
+        verifier_nonce: &VerifierNonce,
+    ) -> Result<round2::SignatureShare, Error> {
+        frost::designated::sign(
+            signing_package,
+            signer_nonces,
+            key_package,
+            verifier_commitment,
+            verifier_nonce,
+        )
+    }
+
+    /// Aggregates the signature shares of a designated-verifier signing operation.
+    pub fn aggregate(
+        signing_package: &SigningPackage,
+        signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+        pubkeys: &keys::PublicKeyPackage,
+        verifier_commitment: &VerifierCommitment,
+        verifier_nonce: &VerifierNonce,
+    ) -> Result<Signature, Error> {
+        frost::designated::aggregate(
+            signing_package,
+            signature_shares,
+            pubkeys,
+            verifier_commitment,
+            verifier_nonce,
+        )
+    }
+
+    /// Verifies a designated-verifier signature on `msg`, using the nonce the
+    /// designated verifier chose.
+    pub fn verify(
+        verifying_key: &VerifyingKey,
+        msg: &[u8],
+        signature: &Signature,
+        verifier_nonce: &VerifierNonce,
+    ) -> Result<(), Error> {
+        frost::designated::verify(verifying_key, msg, signature, verifier_nonce)
+    }
+}
+
+/// Threshold ElGamal decryption with FROST(Ed448, SHAKE256) key shares.
+pub mod decrypt {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    /// An ElGamal ciphertext encrypted to a FROST(Ed448, SHAKE256) group verifying key.
+    pub type Ciphertext = frost::decrypt::Ciphertext<E>;
+
+    /// A FROST(Ed448, SHAKE256) participant's share of the decryption of a [`Ciphertext`].
+    pub type DecryptionShare = frost::decrypt::DecryptionShare<E>;
+
+    /// Performed once by each participant selected for the decryption, i.e. in
+    /// `decryptors`.
+    pub fn decryption_share(
+        ciphertext: &Ciphertext,
+        key_package: &keys::KeyPackage,
+        decryptors: &BTreeSet<Identifier>,
+    ) -> Result<DecryptionShare, Error> {
+        frost::decrypt::decryption_share(ciphertext, key_package, decryptors)
+    }
+
+    /// Combines the decryption shares of all the decryptors to recover the
+    /// plaintext group element.
+    pub fn combine_decryption_shares(
+        ciphertext: &Ciphertext,
+        decryption_shares: &BTreeMap<Identifier, DecryptionShare>,
+    ) -> Result<frost::Element<E>, Error> {
+        frost::decrypt::combine_decryption_shares(ciphertext, decryption_shares)
+    }
 }
 
 /// A Schnorr signature on FROST(Ed448, SHAKE256).
 pub type Signature = frost_core::Signature<E>;
 
+/// Randomly selects `size` participants from the given
+/// [`keys::PublicKeyPackage`] to sign, e.g. when the group is too large to
+/// gather commitments from every participant.
+///
+/// Returns an error if `size` is smaller than `min_signers` or larger than the
+/// number of participants in the group.
+pub fn select_committee<RNG: RngCore + CryptoRng>(
+    pubkeys: &keys::PublicKeyPackage,
+    min_signers: u16,
+    size: u16,
+    rng: RNG,
+) -> Result<Vec<Identifier>, Error> {
+    frost::select_committee(pubkeys, min_signers, size, rng)
+}
+
 /// Verifies each FROST(Ed448, SHAKE256) participant's signature share, and if all are valid,
 /// aggregates the shares into a signature to publish.
 ///
@@ -406,6 +666,119 @@ pub fn aggregate(
     frost::aggregate(signing_package, signature_shares, pubkeys)
 }
 
+/// Deserializes each participant's signature share and aggregates them into a
+/// signature to publish, like [`aggregate`].
+///
+/// If the bytes sent by a participant can't be deserialized, an
+/// [`Error::MalformedSignatureShare`](frost_core::Error::MalformedSignatureShare)
+/// naming that participant is returned.
+pub fn aggregate_from_bytes(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, Vec<u8>>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<Signature, Error> {
+    frost::aggregate_from_bytes(signing_package, signature_shares, pubkeys)
+}
+
+/// Like [`aggregate`], but also checks the share of the group commitment
+/// reported by each participant against their commitments in the signing
+/// package, to detect a participant who signed with other nonces.
+pub fn aggregate_with_commitment_shares(
+    signing_package: &SigningPackage,
+    commitment_shares: &BTreeMap<Identifier, round1::GroupCommitmentShare>,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<Signature, Error> {
+    frost::aggregate_with_commitment_shares(
+        signing_package,
+        commitment_shares,
+        signature_shares,
+        pubkeys,
+    )
+}
+
+/// Like [`aggregate`], but first checks the digest of the signing package
+/// reported by each participant, to detect shares computed over another
+/// signing package, e.g. with a different message.
+pub fn aggregate_with_signing_package_digests(
+    signing_package: &SigningPackage,
+    signing_package_digests: &BTreeMap<Identifier, Vec<u8>>,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<Signature, Error> {
+    frost::aggregate_with_signing_package_digests(
+        signing_package,
+        signing_package_digests,
+        signature_shares,
+        pubkeys,
+    )
+}
+
+/// Aggregates signature shares pulled from an iterator into a signature to
+/// publish, without collecting them, like [`aggregate`].
+///
+/// If the resulting signature is invalid, the shares can't be verified
+/// individually; use [`aggregate`] to find the misbehaving participant.
+pub fn aggregate_streaming<I>(
+    signing_package: &SigningPackage,
+    signature_shares: I,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<Signature, Error>
+where
+    I: IntoIterator<Item = (Identifier, round2::SignatureShare)>,
+{
+    frost::aggregate_streaming(signing_package, signature_shares, pubkeys)
+}
+
+/// Verifies all the FROST(Ed448, SHAKE256) participants' signature shares at once with
+/// a randomized batch check, verifying them one by one to find an invalid
+/// share only if the batch check fails.
+pub fn verify_signature_shares_batch<RNG: RngCore + CryptoRng>(
+    signing_package: &SigningPackage,
+    pubkeys: &keys::PublicKeyPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    rng: RNG,
+) -> Result<(), Error> {
+    frost::verify_signature_shares_batch(signing_package, pubkeys, signature_shares, rng)
+}
+
+/// A report of the contribution of each FROST(Ed448, SHAKE256) participant to a
+/// signing operation.
+pub type SigningReport = frost::SigningReport<E>;
+
+/// Reports the contribution of each FROST(Ed448, SHAKE256) participant to a signing
+/// operation: their Lagrange coefficient, whether their signature share is
+/// valid, and their share of the group commitment.
+pub fn signing_report(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<SigningReport, Error> {
+    frost::signing_report(signing_package, signature_shares, pubkeys)
+}
+
+/// The party that aggregates the FROST(Ed448, SHAKE256) signature shares into the final
+/// signature, which can be different from the coordinator.
+pub type Combiner = frost::Combiner<E>;
+
+/// Returns the exact bytes that are hashed to compute the challenge of a
+/// FROST(Ed448, SHAKE256) signature with group commitment `group_commitment` (the `R`
+/// of the signature) under `verifying_key` on `msg`.
+///
+/// This allows comparing the challenge preimage of external verifiers with
+/// this one byte-by-byte.
+pub fn challenge_preimage(
+    group_commitment: &frost::Element<E>,
+    verifying_key: &VerifyingKey,
+    msg: &[u8],
+) -> Vec<u8> {
+    frost::challenge_preimage(group_commitment, verifying_key, msg)
+}
+
+/// Records the messages signed by each group, to accept at most one signature
+/// per group on each message.
+pub type VerifierReplayCache = frost::replay::VerifierReplayCache<E>;
+
 /// A signing key for a Schnorr signature on FROST(Ed448, SHAKE256).
 pub type SigningKey = frost_core::SigningKey<E>;
 
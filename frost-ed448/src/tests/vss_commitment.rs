@@ -36,3 +36,15 @@ fn check_compute_public_key_package() {
     let rng = thread_rng();
     frost_core::tests::vss_commitment::check_compute_public_key_package::<Ed448Shake256, _>(rng);
 }
+
+#[test]
+fn check_verify_shares() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_verify_shares::<Ed448Shake256, _>(rng);
+}
+
+#[test]
+fn check_vss_commitment_verifying_key() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_vss_commitment_verifying_key::<Ed448Shake256, _>(rng);
+}
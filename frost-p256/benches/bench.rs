@@ -15,5 +15,35 @@ fn bench_p256_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<P256Sha256, _>(c, "p256", &mut rng);
 }
 
-criterion_group!(benches, bench_p256_batch_verify, bench_p256_sign);
+fn bench_p256_group_commitment(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_group_commitment::<P256Sha256, _>(c, "p256", &mut rng);
+}
+
+fn bench_p256_verify_signature_shares_batch(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_verify_signature_shares_batch::<P256Sha256, _>(c, "p256", &mut rng);
+}
+
+fn bench_p256_lambda_cache(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_lambda_cache::<P256Sha256, _>(c, "p256", &mut rng);
+}
+
+fn bench_p256_signing_set_sizes(c: &mut Criterion) {
+    frost_core::benches::bench_signing_set_sizes::<P256Sha256>(c, "p256");
+}
+
+criterion_group!(
+    benches,
+    bench_p256_batch_verify,
+    bench_p256_sign,
+    bench_p256_group_commitment,
+    bench_p256_verify_signature_shares_batch,
+    bench_p256_lambda_cache,
+    bench_p256_signing_set_sizes
+);
 criterion_main!(benches);
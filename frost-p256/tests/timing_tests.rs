@@ -0,0 +1,20 @@
+//! Statistical timing tests, see `frost_core::tests::timing`. Run with
+//! `cargo test --release -p frost-p256 --features timing-tests --test timing_tests`.
+#![cfg(feature = "timing-tests")]
+
+use frost_p256::*;
+use rand::thread_rng;
+
+#[test]
+fn check_signature_share_verify_timing() {
+    let rng = thread_rng();
+
+    frost_core::tests::timing::check_signature_share_verify_timing::<P256Sha256, _>(rng);
+}
+
+#[test]
+fn check_secret_share_verify_timing() {
+    let rng = thread_rng();
+
+    frost_core::tests::timing::check_secret_share_verify_timing::<P256Sha256, _>(rng);
+}
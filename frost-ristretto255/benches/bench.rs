@@ -15,9 +15,43 @@ fn bench_ristretto255_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Ristretto255Sha512, _>(c, "ristretto255", &mut rng);
 }
 
+fn bench_ristretto255_group_commitment(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_group_commitment::<Ristretto255Sha512, _>(
+        c,
+        "ristretto255",
+        &mut rng,
+    );
+}
+
+fn bench_ristretto255_verify_signature_shares_batch(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_verify_signature_shares_batch::<Ristretto255Sha512, _>(
+        c,
+        "ristretto255",
+        &mut rng,
+    );
+}
+
+fn bench_ristretto255_lambda_cache(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_lambda_cache::<Ristretto255Sha512, _>(c, "ristretto255", &mut rng);
+}
+
+fn bench_ristretto255_signing_set_sizes(c: &mut Criterion) {
+    frost_core::benches::bench_signing_set_sizes::<Ristretto255Sha512>(c, "ristretto255");
+}
+
 criterion_group!(
     benches,
     bench_ristretto255_batch_verify,
-    bench_ristretto255_sign
+    bench_ristretto255_sign,
+    bench_ristretto255_group_commitment,
+    bench_ristretto255_verify_signature_shares_batch,
+    bench_ristretto255_lambda_cache,
+    bench_ristretto255_signing_set_sizes
 );
 criterion_main!(benches);
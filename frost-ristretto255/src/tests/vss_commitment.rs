@@ -41,3 +41,17 @@ fn check_compute_public_key_package() {
         rng,
     );
 }
+
+#[test]
+fn check_verify_shares() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_verify_shares::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_vss_commitment_verifying_key() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_vss_commitment_verifying_key::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
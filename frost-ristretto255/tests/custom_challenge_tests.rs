@@ -0,0 +1,80 @@
+//! Swapping only the challenge hash of FROST(ristretto255, SHA-512), e.g. to
+//! experiment with other hash functions, by defining a ciphersuite that
+//! delegates everything else to [`Ristretto255Sha512`].
+
+use curve25519_dalek::scalar::Scalar;
+use frost_ristretto255::*;
+use rand::thread_rng;
+
+/// FROST(ristretto255, SHA-512) with the challenge computed with BLAKE3.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct ExperimentalChallenge;
+
+impl Ciphersuite for ExperimentalChallenge {
+    const ID: &'static str = "FROST-RISTRETTO255-BLAKE3-CHAL-EXPERIMENT";
+
+    type Group = RistrettoGroup;
+
+    type HashOutput = [u8; 64];
+
+    type SignatureSerialization = [u8; 64];
+
+    fn H1(m: &[u8]) -> Scalar {
+        Ristretto255Sha512::H1(m)
+    }
+
+    fn H2(m: &[u8]) -> Scalar {
+        let mut h = blake3::Hasher::new();
+        h.update(Self::ID.as_bytes());
+        h.update(b"chal");
+        h.update(m);
+        // Use the extendable output to reduce a 512-bit value modulo the group
+        // order, like the other hash-to-scalar functions.
+        let mut wide = [0u8; 64];
+        h.finalize_xof().fill(&mut wide);
+        Scalar::from_bytes_mod_order_wide(&wide)
+    }
+
+    fn H3(m: &[u8]) -> Scalar {
+        Ristretto255Sha512::H3(m)
+    }
+
+    fn H4(m: &[u8]) -> Self::HashOutput {
+        Ristretto255Sha512::H4(m)
+    }
+
+    fn H5(m: &[u8]) -> Self::HashOutput {
+        Ristretto255Sha512::H5(m)
+    }
+
+    fn HDKG(m: &[u8]) -> Option<Scalar> {
+        Ristretto255Sha512::HDKG(m)
+    }
+
+    fn HID(m: &[u8]) -> Option<Scalar> {
+        Ristretto255Sha512::HID(m)
+    }
+}
+
+#[test]
+fn check_sign_with_dealer_with_custom_challenge() {
+    let rng = thread_rng();
+
+    // Signs, aggregates and verifies with the same challenge hash.
+    let (message, signature, verifying_key) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<ExperimentalChallenge, _>(
+            rng,
+        );
+
+    // The signature is not valid under the standard challenge hash.
+    let signature = Signature::deserialize(signature.serialize()).unwrap();
+    let verifying_key = VerifyingKey::deserialize(verifying_key.serialize()).unwrap();
+    assert!(verifying_key.verify(&message, &signature).is_err());
+}
+
+#[test]
+fn check_sign_with_dkg_with_custom_challenge() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<ExperimentalChallenge, _>(rng);
+}
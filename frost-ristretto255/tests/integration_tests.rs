@@ -8,6 +8,21 @@ fn check_zero_key_fails() {
     frost_core::tests::ciphersuite_generic::check_zero_key_fails::<Ristretto255Sha512>();
 }
 
+#[test]
+fn check_zero_identifier_fails() {
+    frost_core::tests::ciphersuite_generic::check_zero_identifier_fails::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_keygen_from_coefficients() {
+    frost_core::tests::ciphersuite_generic::check_keygen_from_coefficients::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_identifier_display() {
+    frost_core::tests::ciphersuite_generic::check_identifier_display::<Ristretto255Sha512>();
+}
+
 #[test]
 fn check_sign_with_dkg() {
     let rng = thread_rng();
@@ -15,6 +30,25 @@ fn check_sign_with_dkg() {
     frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<Ristretto255Sha512, _>(rng);
 }
 
+#[test]
+fn check_dkg_secret_packages_zeroize() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_dkg_secret_packages_zeroize::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_key_packages_zeroize() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_packages_zeroize::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
 #[test]
 fn check_dkg_part1_fails_with_invalid_signers_min_signers() {
     let rng = thread_rng();
@@ -64,6 +98,23 @@ fn check_rts() {
     frost_core::tests::repairable::check_rts::<Ristretto255Sha512, _>(rng);
 }
 
+#[test]
+fn check_simulate_repair() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_simulate_repair::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_simulate_repair_with_malicious_helper() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_simulate_repair_with_malicious_helper::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
 #[test]
 fn check_sign_with_dealer() {
     let rng = thread_rng();
@@ -71,6 +122,219 @@ fn check_sign_with_dealer() {
     frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<Ristretto255Sha512, _>(rng);
 }
 
+#[test]
+fn check_secret_share_coefficient_count() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_secret_share_coefficient_count::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_group_commitment_and_binding_factors() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_commitment_and_binding_factors::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregate_from_bytes() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_from_bytes::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_group_commitment_matches_signature() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_commitment_matches_signature::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signature_from_components() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_from_components::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_encoded_lengths() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_encoded_lengths::<Ristretto255Sha512, _>(rng);
+
+    assert_eq!(Signature::encoded_len(), 64);
+    assert_eq!(round2::SignatureShare::encoded_len(), 32);
+    assert_eq!(round1::SigningCommitments::encoded_len(), 64);
+}
+
+#[test]
+fn check_challenge_preimage() {
+    let mut rng = thread_rng();
+
+    let signing_key = SigningKey::new(&mut rng);
+    let verifying_key = VerifyingKey::from(&signing_key);
+    let message = b"message to sign";
+    let signature = signing_key.sign(&mut rng, message);
+    let signature_bytes = signature.serialize();
+
+    let decompress = |bytes: &[u8]| {
+        curve25519_dalek::ristretto::CompressedRistretto::from_slice(bytes)
+            .unwrap()
+            .decompress()
+            .unwrap()
+    };
+    let group_commitment = decompress(&signature_bytes[..32]);
+    let preimage = challenge_preimage(&group_commitment, &verifying_key, message);
+
+    // The challenge input is R || PK || msg, with R and PK in their 32-byte
+    // compressed encodings.
+    let verifying_key_bytes = verifying_key.serialize();
+    assert_eq!(
+        preimage,
+        [
+            &signature_bytes[..32],
+            &verifying_key_bytes[..],
+            &message[..]
+        ]
+        .concat()
+    );
+
+    // Hashing it with H2 gives the challenge the signature was created with.
+    let challenge = Ristretto255Sha512::H2(&preimage);
+    let z =
+        curve25519_dalek::Scalar::from_canonical_bytes(signature_bytes[32..].try_into().unwrap())
+            .unwrap();
+    assert_eq!(
+        curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT * z,
+        group_commitment + decompress(&verifying_key_bytes) * challenge
+    );
+}
+
+#[test]
+fn check_combiner() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_combiner::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_streaming() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_streaming::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_verify_signature_shares_batch() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_signature_shares_batch::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signing_report() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_report::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_aggregate_mismatched_group_public_key() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_mismatched_group_public_key::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_signature_share_with_lambda() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_signature_share_with_lambda::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_provider() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_provider::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_aux_rand() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_aux_rand::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_after_recommit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_after_recommit::<Ristretto255Sha512, _>(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_sign_with_serialized_nonces() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_serialized_nonces::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_key_package_try_from_bytes() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_try_from_bytes::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_serialization_independent_of_insertion_order() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_serialization_independent_of_insertion_order::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_select_committee() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_select_committee::<Ristretto255Sha512, _>(rng);
+}
+
 #[test]
 fn check_sign_with_dealer_fails_with_invalid_min_signers() {
     let rng = thread_rng();
@@ -202,6 +466,21 @@ fn check_identifier_derivation() {
     frost_core::tests::ciphersuite_generic::check_identifier_derivation::<Ristretto255Sha512>();
 }
 
+#[test]
+fn check_identifiers_unique() {
+    frost_core::tests::ciphersuite_generic::check_identifiers_unique::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_identifier_derivation_from_verifying_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_identifier_derivation_from_verifying_share::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
 // Explicit test which is used in a documentation snippet
 #[test]
 #[allow(unused_variables)]
@@ -240,3 +519,243 @@ fn check_sign_with_incorrect_commitments() {
         _,
     >(rng);
 }
+
+#[test]
+fn check_sign_dry_run() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_dry_run::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_split() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_split::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_custodial() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_custodial::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_threshold_decryption() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_threshold_decryption::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_cached() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_cached::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_key_package_against_public_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_against_public_package::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_key_package_verify_against_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_verify_against_commitment::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_verifying_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_verifying_share::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_verification_tuples() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_verification_tuples::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_group_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_group_id::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_prove_possession() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_prove_possession::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_designated() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_designated::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_share_generation_with_derived_identifiers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_share_generation_with_derived_identifiers::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_encoded() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_encoded::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_sign_with_network_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_network_id::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signing_package_new_checked() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_new_checked::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_identical_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_identical_commitments::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signing_package_against_public_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_against_public_package::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_reconstruct_secret() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_reconstruct_secret::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_signing_package_builder() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_builder::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_refresh_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_refresh_shares::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_signing_package_num_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_num_commitments::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_transcript_from_seed() {
+    frost_core::tests::transcript::check_transcript_from_seed::<Ristretto255Sha512>();
+}
+
+#[test]
+fn check_negate() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_negate::<Ristretto255Sha512, _>(rng);
+}
+
+#[test]
+fn check_verifier_replay_cache() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verifier_replay_cache::<Ristretto255Sha512, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_aggregate_with_commitment_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_commitment_shares::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregate_with_signing_package_digests() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_signing_package_digests::<
+        Ristretto255Sha512,
+        _,
+    >(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_codecs() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_codecs::<Ristretto255Sha512, _>(rng);
+}
@@ -0,0 +1,100 @@
+//! Computing the challenge of FROST(ristretto255, SHA-512) with a Merlin
+//! transcript, by defining a ciphersuite whose `H2` absorbs the challenge
+//! preimage into the transcript and that delegates everything else to
+//! [`Ristretto255Sha512`].
+
+use curve25519_dalek::scalar::Scalar;
+use frost_ristretto255::*;
+use merlin::Transcript;
+use rand::thread_rng;
+
+/// FROST(ristretto255, SHA-512) with the challenge squeezed from a Merlin
+/// transcript.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct MerlinChallenge;
+
+impl Ciphersuite for MerlinChallenge {
+    const ID: &'static str = "FROST-RISTRETTO255-MERLIN-CHAL-EXPERIMENT";
+
+    type Group = RistrettoGroup;
+
+    type HashOutput = [u8; 64];
+
+    type SignatureSerialization = [u8; 64];
+
+    fn H1(m: &[u8]) -> Scalar {
+        Ristretto255Sha512::H1(m)
+    }
+
+    fn H2(m: &[u8]) -> Scalar {
+        // Absorb the whole challenge preimage as a single transcript message.
+        let mut transcript = Transcript::new(b"FROST-RISTRETTO255-MERLIN-CHAL-EXPERIMENT");
+        transcript.append_message(b"chal", m);
+        // Squeeze 512 bits to reduce them modulo the group order, like the
+        // other hash-to-scalar functions.
+        let mut wide = [0u8; 64];
+        transcript.challenge_bytes(b"challenge", &mut wide);
+        Scalar::from_bytes_mod_order_wide(&wide)
+    }
+
+    fn H3(m: &[u8]) -> Scalar {
+        Ristretto255Sha512::H3(m)
+    }
+
+    fn H4(m: &[u8]) -> Self::HashOutput {
+        Ristretto255Sha512::H4(m)
+    }
+
+    fn H5(m: &[u8]) -> Self::HashOutput {
+        Ristretto255Sha512::H5(m)
+    }
+
+    fn HDKG(m: &[u8]) -> Option<Scalar> {
+        Ristretto255Sha512::HDKG(m)
+    }
+
+    fn HID(m: &[u8]) -> Option<Scalar> {
+        Ristretto255Sha512::HID(m)
+    }
+}
+
+#[test]
+fn check_merlin_challenge_is_deterministic() {
+    let preimage = b"R || verifying key || message";
+
+    // The same preimage always gives the same challenge.
+    assert_eq!(MerlinChallenge::H2(preimage), MerlinChallenge::H2(preimage));
+
+    // A different preimage gives a different challenge.
+    assert_ne!(
+        MerlinChallenge::H2(preimage),
+        MerlinChallenge::H2(b"R || verifying key || other message")
+    );
+
+    // The challenge is not the standard one.
+    assert_ne!(
+        MerlinChallenge::H2(preimage),
+        Ristretto255Sha512::H2(preimage)
+    );
+}
+
+#[test]
+fn check_sign_with_dealer_with_merlin_challenge() {
+    let rng = thread_rng();
+
+    // Signs, aggregates and verifies with the same challenge.
+    let (message, signature, verifying_key) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<MerlinChallenge, _>(rng);
+
+    // The signature is not valid under the standard challenge hash.
+    let signature = Signature::deserialize(signature.serialize()).unwrap();
+    let verifying_key = VerifyingKey::deserialize(verifying_key.serialize()).unwrap();
+    assert!(verifying_key.verify(&message, &signature).is_err());
+}
+
+#[test]
+fn check_sign_with_dkg_with_merlin_challenge() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<MerlinChallenge, _>(rng);
+}
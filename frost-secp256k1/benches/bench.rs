@@ -15,5 +15,39 @@ fn bench_secp256k1_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Secp256K1Sha256, _>(c, "secp256k1", &mut rng);
 }
 
-criterion_group!(benches, bench_secp256k1_batch_verify, bench_secp256k1_sign);
+fn bench_secp256k1_group_commitment(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_group_commitment::<Secp256K1Sha256, _>(c, "secp256k1", &mut rng);
+}
+
+fn bench_secp256k1_verify_signature_shares_batch(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_verify_signature_shares_batch::<Secp256K1Sha256, _>(
+        c,
+        "secp256k1",
+        &mut rng,
+    );
+}
+
+fn bench_secp256k1_lambda_cache(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_lambda_cache::<Secp256K1Sha256, _>(c, "secp256k1", &mut rng);
+}
+
+fn bench_secp256k1_signing_set_sizes(c: &mut Criterion) {
+    frost_core::benches::bench_signing_set_sizes::<Secp256K1Sha256>(c, "secp256k1");
+}
+
+criterion_group!(
+    benches,
+    bench_secp256k1_batch_verify,
+    bench_secp256k1_sign,
+    bench_secp256k1_group_commitment,
+    bench_secp256k1_verify_signature_shares_batch,
+    bench_secp256k1_lambda_cache,
+    bench_secp256k1_signing_set_sizes
+);
 criterion_main!(benches);
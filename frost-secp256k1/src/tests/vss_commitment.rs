@@ -36,3 +36,17 @@ fn check_compute_public_key_package() {
     let rng = thread_rng();
     frost_core::tests::vss_commitment::check_compute_public_key_package::<Secp256K1Sha256, _>(rng);
 }
+
+#[test]
+fn check_verify_shares() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_verify_shares::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_vss_commitment_verifying_key() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_vss_commitment_verifying_key::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
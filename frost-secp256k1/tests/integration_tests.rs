@@ -8,6 +8,21 @@ fn check_zero_key_fails() {
     frost_core::tests::ciphersuite_generic::check_zero_key_fails::<Secp256K1Sha256>();
 }
 
+#[test]
+fn check_zero_identifier_fails() {
+    frost_core::tests::ciphersuite_generic::check_zero_identifier_fails::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_keygen_from_coefficients() {
+    frost_core::tests::ciphersuite_generic::check_keygen_from_coefficients::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_identifier_display() {
+    frost_core::tests::ciphersuite_generic::check_identifier_display::<Secp256K1Sha256>();
+}
+
 #[test]
 fn check_sign_with_dkg() {
     let rng = thread_rng();
@@ -15,6 +30,22 @@ fn check_sign_with_dkg() {
     frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<Secp256K1Sha256, _>(rng);
 }
 
+#[test]
+fn check_dkg_secret_packages_zeroize() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_dkg_secret_packages_zeroize::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_key_packages_zeroize() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_packages_zeroize::<Secp256K1Sha256, _>(rng);
+}
+
 #[test]
 fn check_dkg_part1_fails_with_invalid_signers_min_signers() {
     let rng = thread_rng();
@@ -64,6 +95,22 @@ fn check_rts() {
     frost_core::tests::repairable::check_rts::<Secp256K1Sha256, _>(rng);
 }
 
+#[test]
+fn check_simulate_repair() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_simulate_repair::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_simulate_repair_with_malicious_helper() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_simulate_repair_with_malicious_helper::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
 #[test]
 fn check_sign_with_dealer() {
     let rng = thread_rng();
@@ -71,6 +118,168 @@ fn check_sign_with_dealer() {
     frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<Secp256K1Sha256, _>(rng);
 }
 
+#[test]
+fn check_secret_share_coefficient_count() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_secret_share_coefficient_count::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_group_commitment_and_binding_factors() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_commitment_and_binding_factors::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregate_from_bytes() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_from_bytes::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_group_commitment_matches_signature() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_group_commitment_matches_signature::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signature_from_components() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signature_from_components::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_encoded_lengths() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_encoded_lengths::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_combiner() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_combiner::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_streaming() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_streaming::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_verify_signature_shares_batch() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_signature_shares_batch::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_signing_report() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_report::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_mismatched_group_public_key() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_mismatched_group_public_key::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_signature_share_with_lambda() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_signature_share_with_lambda::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_sign_with_provider() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_provider::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_aux_rand() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_aux_rand::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_after_recommit() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_after_recommit::<Secp256K1Sha256, _>(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_sign_with_serialized_nonces() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_serialized_nonces::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_key_package_try_from_bytes() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_try_from_bytes::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_serialization_independent_of_insertion_order() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_serialization_independent_of_insertion_order::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_select_committee() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_select_committee::<Secp256K1Sha256, _>(rng);
+}
+
 #[test]
 fn check_sign_with_dealer_fails_with_invalid_min_signers() {
     let rng = thread_rng();
@@ -202,6 +411,21 @@ fn check_identifier_derivation() {
     frost_core::tests::ciphersuite_generic::check_identifier_derivation::<Secp256K1Sha256>();
 }
 
+#[test]
+fn check_identifiers_unique() {
+    frost_core::tests::ciphersuite_generic::check_identifiers_unique::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_identifier_derivation_from_verifying_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_identifier_derivation_from_verifying_share::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
 // Explicit test which is used in a documentation snippet
 #[test]
 #[allow(unused_variables)]
@@ -239,3 +463,266 @@ fn check_sign_with_incorrect_commitments() {
         _,
     >(rng);
 }
+
+#[test]
+fn check_sign_dry_run() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_dry_run::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_split() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_split::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_custodial() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_custodial::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_threshold_decryption() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_threshold_decryption::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_cached() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_cached::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_key_package_against_public_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_against_public_package::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_key_package_verify_against_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_key_package_verify_against_commitment::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_verifying_share() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_verifying_share::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_verification_tuples() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_verification_tuples::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_public_key_package_group_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_public_key_package_group_id::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_prove_possession() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_prove_possession::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_designated() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_designated::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_share_generation_with_derived_identifiers() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_share_generation_with_derived_identifiers::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verify_encoded() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verify_encoded::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_network_id() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_network_id::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signing_package_new_checked() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_new_checked::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_with_identical_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_identical_commitments::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_signing_package_against_public_package() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_against_public_package::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_reconstruct_secret() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_reconstruct_secret::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_verify_any_parity() {
+    let mut rng = thread_rng();
+    let msg = b"message to sign";
+    let signing_key = SigningKey::new(&mut rng);
+    let verifying_key = VerifyingKey::from(&signing_key);
+
+    // Sign until signatures with both an even-Y and an odd-Y `R` are found.
+    let mut found = [false; 2];
+    while found != [true, true] {
+        let signature = signing_key.sign(&mut rng, msg);
+        let bytes = signature.serialize();
+        let r_x: [u8; 32] = bytes[1..33].try_into().unwrap();
+        let s = Secp256K1ScalarField::deserialize(&bytes[33..].try_into().unwrap()).unwrap();
+
+        assert!(verify_any_parity(&verifying_key, msg, r_x, s).is_ok());
+        assert_eq!(
+            verify_any_parity(&verifying_key, b"another message", r_x, s),
+            Err(Error::InvalidSignature)
+        );
+        found[(bytes[0] - 0x02) as usize] = true;
+    }
+
+    // An x-coordinate that is not a field element is rejected.
+    let s = Secp256K1ScalarField::one();
+    assert_eq!(
+        verify_any_parity(&verifying_key, msg, [0xff; 32], s),
+        Err(Error::MalformedSignature)
+    );
+}
+
+#[test]
+fn check_signing_package_builder() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_builder::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_refresh_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_refresh_shares::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signing_package_num_commitments() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_signing_package_num_commitments::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_transcript_from_seed() {
+    frost_core::tests::transcript::check_transcript_from_seed::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_negate() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_negate::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_verifier_replay_cache() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_verifier_replay_cache::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_aggregate_with_commitment_shares() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_commitment_shares::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_aggregate_with_signing_package_digests() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_aggregate_with_signing_package_digests::<
+        Secp256K1Sha256,
+        _,
+    >(rng);
+}
+
+#[cfg(feature = "serialization")]
+#[test]
+fn check_codecs() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_codecs::<Secp256K1Sha256, _>(rng);
+}